@@ -1,5 +1,20 @@
 pub mod widget;
 
-pub use widget::hsv::{self, Hsv, hsv, hsva};
+pub use widget::cmyk::{self, Cmyk, cmyk};
+pub use widget::contrast::{self, apca_contrast, contrast_ratio, readable_foreground};
+pub use widget::delta_e;
+pub use widget::gamut::{self, Gamut, GamutColor, GamutMapping};
+pub use widget::gradient::{self, ColorStop, Gradient, Interpolation};
+pub use widget::harmony::{self, Harmony};
+pub use widget::hsv::{self, Hsv, WorkingSpace, hsv, hsva, wrap_hue};
+pub use widget::hwb::{self, Hwb, hwb};
+pub use widget::lab::{self, Lab, Lch, Xyz};
+pub use widget::oklab::{self, Oklab, Oklch};
+pub use widget::readout::{self, Readout};
+pub use widget::snap::{self, Snap};
 pub use widget::style::{self, Catalog, MarkerShape, Style, StyleFn};
-pub use widget::{ColorPicker, Spectrum, color_picker};
+pub use widget::temperature::{self, color_to_kelvin, kelvin_to_color};
+pub use widget::{
+    ButtonAction, ColorPicker, Orientation, SecondaryAction, Spectrum, color_picker,
+    multi_color_picker,
+};
@@ -1,14 +1,39 @@
 //! A widget to display and pick colors.
 
+pub mod cmyk;
+pub mod contrast;
+pub mod delta_e;
+pub mod gamut;
+pub mod gradient;
+pub mod harmony;
 pub mod hsv;
+pub mod hwb;
+pub mod lab;
+pub mod oklab;
+pub mod readout;
+pub mod snap;
 pub mod spectrums;
 pub mod style;
+pub mod temperature;
 
-pub use hsv::{Hsv, hsv};
+pub use cmyk::{Cmyk, cmyk};
+pub use gamut::{Gamut, GamutColor, GamutMapping};
+pub use gradient::{Gradient, Interpolation};
+pub use harmony::Harmony;
+pub use hsv::{Hsv, WorkingSpace, hsv};
+pub use hwb::{Hwb, hwb};
+pub use lab::{Lab, Lch, Xyz};
+pub use oklab::{Oklab, Oklch};
+pub use readout::Readout;
+pub use snap::Snap;
 
+use iced_core::time::{Duration, Instant};
 use iced_core::widget::{Tree, Widget, tree};
-use iced_core::{Color, Element, Length, Point, Rectangle, Size, Vector, layout, mouse, touch};
-use iced_graphics::geometry::{self, Frame, Path};
+use iced_core::{
+    Color, Element, Length, Point, Rectangle, Size, Vector, alignment, keyboard, layout, mouse,
+    overlay, touch, window,
+};
+use iced_graphics::geometry::{self, Frame, Path, Text};
 
 use style::{Catalog, MarkerShape, Style, StyleFn};
 
@@ -25,30 +50,157 @@ where
     ColorPicker::new(color, move |color| on_select(color.into()))
 }
 
+/// Creates a new [ColorPicker] with a marker for each color,
+/// and a closure to produce a message with the index of the marker when a color is picked.
+pub fn multi_color_picker<'a, Message, Theme, FromHsv>(
+    colors: impl IntoIterator<Item = impl Into<Hsv>>,
+    on_select: impl Fn(usize, FromHsv) -> Message + 'a,
+) -> ColorPicker<'a, Message, Theme>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    FromHsv: From<Hsv> + 'a,
+{
+    ColorPicker::new_multi(colors, move |index, color| on_select(index, color.into()))
+}
+
 /// The range of colors displayed by the [ColorPicker].
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Spectrum {
     /// A 2-Dimensional spectrum where the saturation changes along the x-axis,
     /// and the value changes along the y-axis.
     SaturationValue,
+    /// A 2-Dimensional spectrum where the [Hwb] whiteness changes along the x-axis,
+    /// and the blackness changes along the y-axis.
+    ///
+    /// Past the diagonal, the whiteness and blackness add up to more than 1 and produce a grey.
+    WhitenessBlackness,
     /// A 1-Dimensional spectrum where the hue changes along the x-axis.
     HueHorizontal,
     /// A 1-Dimensional spectrum where the hue changes along the y-axis.
     HueVertical,
+    /// A ring where the hue increases counter-clockwise, starting from the right.
+    HueRing,
+    /// A 1-Dimensional spectrum where the alpha changes, drawn over a checkerboard.
+    Alpha(Orientation),
+    /// A 1-Dimensional spectrum where the red component changes,
+    /// while the green and blue components are fixed.
+    Red(Orientation),
+    /// A 1-Dimensional spectrum where the green component changes,
+    /// while the red and blue components are fixed.
+    Green(Orientation),
+    /// A 1-Dimensional spectrum where the blue component changes,
+    /// while the red and green components are fixed.
+    Blue(Orientation),
+    /// A 1-Dimensional spectrum where the [Cmyk] cyan component changes.
+    Cyan(Orientation),
+    /// A 1-Dimensional spectrum where the [Cmyk] magenta component changes.
+    Magenta(Orientation),
+    /// A 1-Dimensional spectrum where the [Cmyk] yellow component changes.
+    Yellow(Orientation),
+    /// A 1-Dimensional spectrum where the [Cmyk] key (black) component changes.
+    Black(Orientation),
+    /// A 1-Dimensional spectrum where the temperature of a blackbody radiator changes.
+    ///
+    /// The temperatures are clamped between [temperature::MIN_KELVIN] and [temperature::MAX_KELVIN].
+    /// See [temperature::kelvin_to_color].
+    Temperature {
+        /// The temperature at the start of the spectrum.
+        min_kelvin: f32,
+        /// The temperature at the end of the spectrum.
+        max_kelvin: f32,
+        /// The axis the temperature changes along.
+        orientation: Orientation,
+    },
+    /// A 1-Dimensional spectrum displaying a [Gradient].
+    ///
+    /// Picking a color produces the color of the gradient under the cursor.
+    Gradient(Gradient, Orientation),
+}
+
+/// The axis a 1-Dimensional [Spectrum] changes along.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Orientation {
+    /// The value increases along the x-axis.
+    #[default]
+    Horizontal,
+    /// The value increases up the y-axis.
+    Vertical,
+}
+
+/// What the [ColorPicker] does when it's pressed with a mouse button.
+#[derive(Default)]
+pub enum ButtonAction<'a, Message> {
+    /// Do nothing.
+    #[default]
+    Ignore,
+    /// Pick a color with the `on_select` of the [ColorPicker].
+    Select,
+    /// Pick a color with a different function, given the index of the grabbed marker.
+    ///
+    /// This can be used to sample a color without committing it.
+    Alternate(Box<dyn Fn(usize, Hsv) -> Message + 'a>),
+    /// Request a context menu, given the position of the cursor in the window and the color under it.
+    ///
+    /// The color isn't picked, and there's no dragging.
+    Context(Box<dyn Fn(Point, Hsv) -> Message + 'a>),
+    /// Publish a message without picking a color, such as stepping through a color history.
+    Trigger(Box<dyn Fn() -> Message + 'a>),
+}
+
+impl<'a, Message> ButtonAction<'a, Message> {
+    /// Pick an alternate color with the button.
+    pub fn alternate<FromHsv: From<Hsv>>(on_select: impl Fn(FromHsv) -> Message + 'a) -> Self {
+        Self::Alternate(Box::new(move |_, color| on_select(color.into())))
+    }
+
+    /// Request a context menu with the button.
+    pub fn context<FromHsv: From<Hsv>>(
+        on_context: impl Fn(Point, FromHsv) -> Message + 'a,
+    ) -> Self {
+        Self::Context(Box::new(move |position, color| {
+            on_context(position, color.into())
+        }))
+    }
+
+    /// Publish a message with the button.
+    pub fn trigger(on_press: impl Fn() -> Message + 'a) -> Self {
+        Self::Trigger(Box::new(on_press))
+    }
 }
 
+/// What the [ColorPicker] does when it's pressed with the right mouse button.
+///
+/// See [ColorPicker::secondary_action].
+pub type SecondaryAction<'a, Message> = ButtonAction<'a, Message>;
+
 /// A widget that can be used to select colors.
 pub struct ColorPicker<'a, Message, Theme>
 where
     Message: 'a,
     Theme: Catalog,
 {
-    color: Hsv,
+    colors: Vec<Hsv>,
     width: Length,
     height: Length,
-    on_select: Box<dyn Fn(Hsv) -> Message + 'a>,
-    on_select_alt: Option<Box<dyn Fn(Hsv) -> Message + 'a>>,
+    on_select: Box<dyn Fn(usize, Hsv) -> Message + 'a>,
+    buttons: Vec<(mouse::Button, ButtonAction<'a, Message>)>,
+    on_rotate: Option<Box<dyn Fn(Vec<Hsv>) -> Message + 'a>>,
+    unlink: keyboard::Modifiers,
     spectrum: Spectrum,
+    working_space: WorkingSpace,
+    gamut: Gamut,
+    gamut_mapping: GamutMapping,
+    contrast: Option<(Color, Vec<f32>)>,
+    restrict_contrast: bool,
+    harmony: Option<Harmony>,
+    snap: Snap,
+    ticks: usize,
+    tick_labels: bool,
+    readout: Option<Readout>,
+    default_color: Option<Hsv>,
     class: Theme::Class<'a>,
 }
 
@@ -57,13 +209,37 @@ where
     Theme: Catalog,
 {
     pub fn new(color: impl Into<Hsv>, on_select: impl Fn(Hsv) -> Message + 'a) -> Self {
+        Self::new_multi([color.into()], move |_, color| on_select(color))
+    }
+
+    /// Create a [ColorPicker] with a marker for each color.
+    ///
+    /// Markers are grabbed by pressing on them, and `on_select` is given the index of the grabbed marker.
+    /// The [Spectrum] is drawn for the first color.
+    pub fn new_multi(
+        colors: impl IntoIterator<Item = impl Into<Hsv>>,
+        on_select: impl Fn(usize, Hsv) -> Message + 'a,
+    ) -> Self {
         Self {
-            color: color.into(),
+            colors: colors.into_iter().map(Into::into).collect(),
             width: Length::Fill,
             height: Length::Fill,
             on_select: Box::new(on_select),
-            on_select_alt: None,
+            buttons: vec![(mouse::Button::Left, ButtonAction::Select)],
+            on_rotate: None,
+            unlink: keyboard::Modifiers::empty(),
             spectrum: Spectrum::SaturationValue,
+            working_space: WorkingSpace::default(),
+            gamut: Gamut::default(),
+            gamut_mapping: GamutMapping::default(),
+            contrast: None,
+            restrict_contrast: false,
+            harmony: None,
+            snap: Snap::default(),
+            ticks: 0,
+            tick_labels: false,
+            readout: None,
+            default_color: None,
             class: Theme::default(),
         }
     }
@@ -74,6 +250,95 @@ where
         self
     }
 
+    /// Set the [WorkingSpace] the [ColorPicker] interprets [Hsv] values in.
+    ///
+    /// This affects both the colors drawn by the [Spectrum] and the marker.
+    pub fn working_space(mut self, space: WorkingSpace) -> Self {
+        self.working_space = space;
+        self
+    }
+
+    /// Set the [Gamut] the [ColorPicker] interprets [Hsv] values in.
+    ///
+    /// When a wide [Gamut] is used, [Spectrum::SaturationValue] outlines the region that fits in sRGB.
+    /// Use [GamutColor::from_hsv] to recover the picked color.
+    pub fn gamut(mut self, gamut: Gamut) -> Self {
+        self.gamut = gamut;
+        self
+    }
+
+    /// Set the [GamutMapping] used to display colors of a wide [Gamut] in sRGB.
+    ///
+    /// Defaults to [GamutMapping::Clip].
+    pub fn gamut_mapping(mut self, mapping: GamutMapping) -> Self {
+        self.gamut_mapping = mapping;
+        self
+    }
+
+    /// Outline where the [contrast_ratio](contrast::contrast_ratio) against the background crosses each threshold,
+    /// such as [contrast::AA] and [contrast::AA_LARGE].
+    ///
+    /// The outlines are only drawn by [Spectrum::SaturationValue].
+    pub fn contrast_against(
+        mut self,
+        background: impl Into<Color>,
+        thresholds: impl IntoIterator<Item = f32>,
+    ) -> Self {
+        self.contrast = Some((background.into(), thresholds.into_iter().collect()));
+        self
+    }
+
+    /// Prevent picking colors that fall below every threshold given to [ColorPicker::contrast_against].
+    ///
+    /// Like the outlines, the contrast is measured with the [GamutMapping] used to draw the [Spectrum],
+    /// so [GamutMapping::Closest] is measured as [GamutMapping::ReduceChroma].
+    pub fn restrict_contrast(mut self, restrict: bool) -> Self {
+        self.restrict_contrast = restrict;
+        self
+    }
+
+    /// Draw secondary markers for the other colors of a [Harmony].
+    ///
+    /// The markers are only drawn by [Spectrum::HueHorizontal], [Spectrum::HueVertical] and [Spectrum::HueRing].
+    /// Dragging one rotates the first color by the same amount, so the scheme turns as a whole.
+    pub fn harmony(mut self, harmony: Harmony) -> Self {
+        self.harmony = Some(harmony);
+        self
+    }
+
+    /// Snap picked colors to steps, or to the nearest color of a palette.
+    pub fn snap(mut self, snap: Snap) -> Self {
+        self.snap = snap;
+        self
+    }
+
+    /// Draw ticks dividing 1-Dimensional spectrums into equal parts,
+    /// such as `12` for every 30° of hue, or `4` for every 25% of alpha.
+    ///
+    /// When this isn't set, hue spectrums have a tick for each hue step of the [Snap].
+    pub fn ticks(mut self, divisions: usize) -> Self {
+        self.ticks = divisions;
+        self
+    }
+
+    /// Label the ticks with their value.
+    pub fn tick_labels(mut self, labels: bool) -> Self {
+        self.tick_labels = labels;
+        self
+    }
+
+    /// Show the value of the grabbed marker next to it while dragging.
+    pub fn readout(mut self, readout: Readout) -> Self {
+        self.readout = Some(readout);
+        self
+    }
+
+    /// Reset the grabbed marker to the default color when it's double-clicked, or long pressed on touch.
+    pub fn default_color(mut self, color: impl Into<Hsv>) -> Self {
+        self.default_color = Some(color.into());
+        self
+    }
+
     /// Set the width of the [ColorPicker].
     pub fn width(mut self, width: impl Into<Length>) -> Self {
         self.width = width.into();
@@ -87,11 +352,48 @@ where
     }
 
     /// Set function that will be called when a color is picked with the right mouse button.
+    ///
+    /// This is the same as [ButtonAction::alternate] on the right mouse button.
     pub fn on_select_alt<FromHsv: From<Hsv>>(
-        mut self,
+        self,
         on_select_alt: impl Fn(FromHsv) -> Message + 'a,
     ) -> Self {
-        self.on_select_alt = Some(Box::new(move |color| on_select_alt(color.into())));
+        self.on_button(mouse::Button::Right, ButtonAction::alternate(on_select_alt))
+    }
+
+    /// Set what happens when the [ColorPicker] is pressed with the right mouse button.
+    ///
+    /// This is the same as [ColorPicker::on_button] with [mouse::Button::Right].
+    pub fn secondary_action(self, action: SecondaryAction<'a, Message>) -> Self {
+        self.on_button(mouse::Button::Right, action)
+    }
+
+    /// Set what happens when the [ColorPicker] is pressed with a mouse button, replacing any previous action.
+    ///
+    /// The left mouse button defaults to [ButtonAction::Select], every other button to [ButtonAction::Ignore].
+    /// Only one button can drag at a time.
+    pub fn on_button(mut self, button: mouse::Button, action: ButtonAction<'a, Message>) -> Self {
+        self.buttons.retain(|(mapped, _)| *mapped != button);
+        self.buttons.push((button, action));
+        self
+    }
+
+    /// Drag every marker together on a hue [Spectrum], rotating their hues while keeping the offsets between them.
+    ///
+    /// `on_rotate` is given every color after the rotation. While the `unlink` modifiers are held,
+    /// only the grabbed marker is dragged, and it's published with `on_select` instead.
+    ///
+    /// The modifiers only unlink the colors given to [ColorPicker::new_multi] or [multi_color_picker].
+    /// A [Harmony] marker follows its base color, so dragging one always turns the whole scheme.
+    pub fn on_rotate<FromHsv: From<Hsv>>(
+        mut self,
+        on_rotate: impl Fn(Vec<FromHsv>) -> Message + 'a,
+        unlink: keyboard::Modifiers,
+    ) -> Self {
+        self.on_rotate = Some(Box::new(move |colors| {
+            on_rotate(colors.into_iter().map(Into::into).collect())
+        }));
+        self.unlink = unlink;
         self
     }
 
@@ -111,6 +413,240 @@ where
     }
 }
 
+impl<'a, Message, Theme> ColorPicker<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// Whether the color passes the lowest threshold of [ColorPicker::contrast_against], if restricted.
+    fn is_selectable(&self, color: Hsv) -> bool {
+        let Some((background, thresholds)) = &self.contrast else {
+            return true;
+        };
+
+        if !self.restrict_contrast {
+            return true;
+        }
+
+        let foreground = Hsv::from(self.gamut.display(
+            color,
+            self.working_space,
+            self.gamut_mapping.spectrum(),
+        ));
+        let ratio = contrast::contrast_ratio(foreground, Hsv::from(*background));
+
+        thresholds
+            .iter()
+            .copied()
+            .reduce(f32::min)
+            .is_none_or(|threshold| ratio >= threshold)
+    }
+
+    fn action(&self, button: mouse::Button) -> &ButtonAction<'a, Message> {
+        self.buttons
+            .iter()
+            .find(|(mapped, _)| *mapped == button)
+            .map_or(&ButtonAction::Ignore, |(_, action)| action)
+    }
+
+    fn on_select(&self, pressed: Pressed) -> Option<&dyn Fn(usize, Hsv) -> Message> {
+        let button = match pressed {
+            Pressed::Mouse(button) => button,
+            Pressed::Finger(_) => return Some(self.on_select.as_ref()),
+        };
+
+        match self.action(button) {
+            ButtonAction::Select => Some(self.on_select.as_ref()),
+            ButtonAction::Alternate(on_select_alt) => Some(on_select_alt.as_ref()),
+            ButtonAction::Ignore | ButtonAction::Context(_) | ButtonAction::Trigger(_) => None,
+        }
+    }
+
+    /// Whether the press picks with `on_select`, rather than an alternate function.
+    fn is_primary(&self, pressed: Pressed) -> bool {
+        match pressed {
+            Pressed::Mouse(button) => matches!(self.action(button), ButtonAction::Select),
+            Pressed::Finger(_) => true,
+        }
+    }
+
+    /// The index of the marker under the cursor, and the hue offset of the [Harmony] marker if one was pressed.
+    ///
+    /// A lone marker is grabbed when nothing else is under the cursor, and jumps to the cursor.
+    fn grab<Renderer: geometry::Renderer>(
+        &self,
+        state: &State<Renderer>,
+        bounds: Rectangle,
+        cursor: Point,
+    ) -> Option<(usize, f32)> {
+        const GRAB_RADIUS: f32 = 12.0;
+
+        let colors = &state.current_colors;
+
+        let cursor = Point::ORIGIN + (cursor - bounds.position());
+
+        // Later markers are drawn on top, so they're preferred.
+        let markers = colors
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(index, color)| (index, 0.0, *color, state.gradient_offset(index)));
+
+        // The secondary markers of a harmony belong to the first color.
+        let harmony = self
+            .harmony
+            .filter(|_| {
+                matches!(
+                    self.spectrum,
+                    Spectrum::HueHorizontal | Spectrum::HueVertical | Spectrum::HueRing
+                )
+            })
+            .zip(colors.first())
+            .into_iter()
+            .flat_map(|(harmony, base)| {
+                harmony
+                    .offsets()
+                    .iter()
+                    .skip(1)
+                    .map(|offset| (0, *offset, base.with_h(base.h + offset), None))
+            });
+
+        markers
+            .chain(harmony)
+            .map(|(index, offset, color, gradient_offset)| {
+                let marker = marker(
+                    &self.spectrum,
+                    color,
+                    gradient_offset,
+                    bounds.size(),
+                    self.working_space,
+                    self.gamut,
+                    self.gamut_mapping,
+                );
+
+                (index, offset, marker.position.distance(cursor))
+            })
+            .filter(|(_, _, distance)| *distance <= GRAB_RADIUS)
+            .min_by(|(_, _, a), (_, _, b)| a.total_cmp(b))
+            .map(|(index, offset, _)| (index, offset))
+            .or((colors.len() == 1).then_some((0, 0.0)))
+    }
+
+    /// The values from `0.0` to `1.0` to draw ticks at, such as `0.25` for 90° of hue.
+    fn tick_values(&self) -> Vec<f32> {
+        // Skip hue steps too small to tell apart.
+        const MIN_HUE_STEP: f32 = 1.0;
+
+        if self.ticks > 0 {
+            return (0..self.ticks)
+                .map(|i| i as f32 / self.ticks as f32)
+                .collect();
+        }
+
+        match self.snap.hue {
+            Some(step)
+                if step >= MIN_HUE_STEP
+                    && matches!(
+                        self.spectrum,
+                        Spectrum::HueHorizontal | Spectrum::HueVertical | Spectrum::HueRing
+                    ) =>
+            {
+                (0..)
+                    .map(|i| i as f32 * step / 360.0)
+                    .take_while(|value| *value < 1.0)
+                    .collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn drawn(&self) -> Drawn {
+        Drawn {
+            spectrum: self.spectrum.clone(),
+            working_space: self.working_space,
+            gamut: self.gamut,
+            gamut_mapping: self.gamut_mapping,
+            contrast: self.contrast.clone(),
+            ticks: self.tick_values(),
+            tick_labels: self.tick_labels,
+        }
+    }
+
+    /// Publish the default color for a marker. Returns `false` if there isn't a default color.
+    fn reset(&self, shell: &mut iced_core::Shell<'_, Message>, index: usize) -> bool {
+        let Some(default_color) = self.default_color else {
+            return false;
+        };
+
+        shell.publish((self.on_select)(index, default_color));
+        true
+    }
+
+    /// Publish the color under the cursor for the grabbed marker.
+    fn pick<Renderer: geometry::Renderer>(
+        &self,
+        shell: &mut iced_core::Shell<'_, Message>,
+        state: &mut State<Renderer>,
+        bounds: Rectangle,
+        cursor: Point,
+    ) {
+        let Some(pressed) = state.pressed else {
+            return;
+        };
+
+        let Some(&current_color) = state.current_colors.get(state.grabbed) else {
+            return;
+        };
+
+        // A harmony marker is dragged by moving the first color, keeping the offset between them.
+        let picked = fetch_hsv(
+            &self.spectrum,
+            current_color.with_h(current_color.h + state.harmony_offset),
+            bounds,
+            cursor,
+            self.working_space,
+            self.gamut,
+        );
+        let new_color = self
+            .snap
+            .apply(picked.with_h(picked.h - state.harmony_offset));
+
+        if !self.is_selectable(new_color) {
+            return;
+        }
+
+        let unlinked = !self.unlink.is_empty() && state.modifiers.contains(self.unlink);
+
+        if let Some(on_rotate) = &self.on_rotate
+            && !unlinked
+            && self.is_primary(pressed)
+            && matches!(
+                self.spectrum,
+                Spectrum::HueHorizontal | Spectrum::HueVertical | Spectrum::HueRing
+            )
+        {
+            let rotation = new_color.h - current_color.h;
+
+            let colors = state
+                .current_colors
+                .iter()
+                .enumerate()
+                .map(|(index, color)| match index == state.grabbed {
+                    true => new_color,
+                    false => color.with_h(color.h + rotation),
+                })
+                .collect();
+
+            shell.publish(on_rotate(colors));
+        } else if let Some(on_select) = self.on_select(pressed) {
+            if let Spectrum::Gradient(_, orientation) = self.spectrum {
+                state.set_gradient_offset(strip_value(orientation, bounds, cursor));
+            }
+
+            shell.publish(on_select(state.grabbed, new_color));
+        }
+    }
+}
+
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for ColorPicker<'a, Message, Theme>
 where
@@ -164,103 +700,214 @@ where
         shell: &mut iced_core::Shell<'_, Message>,
         _viewport: &Rectangle,
     ) {
-        let State {
-            spectrum_cache,
-            pressed,
-            current_color,
-            marker_cache,
-        }: &mut State<Renderer> = tree.state.downcast_mut();
+        // How long a finger has to be held to reset the color.
+        const LONG_PRESS: Duration = Duration::from_millis(500);
+        // How far a finger can move before it stops being a long press.
+        const TOUCH_SLOP: f32 = 8.0;
+
+        let state: &mut State<Renderer> = tree.state.downcast_mut();
 
         let cursor_in_bounds = cursor.is_over(layout.bounds());
         let bounds = layout.bounds();
 
+        let drawn = self.drawn();
+
+        if state.drawn.as_ref() != Some(&drawn) {
+            state.drawn = Some(drawn);
+            state.gradient_offsets.clear();
+            state.spectrum_cache.clear();
+            state.marker_cache.clear();
+            shell.request_redraw();
+        }
+
         if diff(
-            self.spectrum,
-            spectrum_cache,
-            marker_cache,
-            current_color,
-            self.color,
+            &self.spectrum,
+            &state.spectrum_cache,
+            &state.marker_cache,
+            &mut state.current_colors,
+            &self.colors,
         ) {
             shell.request_redraw();
         }
 
         match event {
             iced_core::Event::Mouse(mouse_event) => match mouse_event {
-                mouse::Event::ButtonReleased(mouse_button) => match (mouse_button, *pressed) {
-                    (mouse::Button::Left, Some(Pressed::Primary)) => *pressed = None,
-                    (mouse::Button::Right, Some(Pressed::Secondary)) => *pressed = None,
-                    _ => (),
-                },
+                mouse::Event::ButtonReleased(mouse_button)
+                    if state.pressed == Some(Pressed::Mouse(*mouse_button)) =>
+                {
+                    state.pressed = None;
+
+                    // Hide the readout.
+                    if self.readout.is_some() {
+                        shell.request_redraw();
+                    }
+                }
                 mouse::Event::ButtonPressed(mouse_button)
-                    if cursor_in_bounds && pressed.is_none() =>
+                    if cursor_in_bounds && state.pressed.is_none() =>
                 {
                     let Some(cursor) = cursor.position() else {
                         return;
                     };
 
-                    let (new_pressed, on_select) = match mouse_button {
-                        mouse::Button::Left => (Pressed::Primary, Some(self.on_select.as_ref())),
-                        mouse::Button::Right => (Pressed::Secondary, self.on_select_alt.as_deref()),
-                        _ => return,
+                    let new_pressed = Pressed::Mouse(*mouse_button);
+
+                    if let ButtonAction::Trigger(on_press) = self.action(*mouse_button) {
+                        shell.publish(on_press());
+                        return;
+                    }
+
+                    if let ButtonAction::Context(on_context) = self.action(*mouse_button) {
+                        let (index, _) = self.grab(state, bounds, cursor).unwrap_or_default();
+
+                        if let Some(&current_color) = state.current_colors.get(index) {
+                            let color = self.snap.apply(fetch_hsv(
+                                &self.spectrum,
+                                current_color,
+                                bounds,
+                                cursor,
+                                self.working_space,
+                                self.gamut,
+                            ));
+
+                            shell.publish(on_context(cursor, color));
+                        }
+
+                        return;
+                    }
+
+                    if self.on_select(new_pressed).is_none() {
+                        return;
+                    }
+
+                    let Some((index, harmony_offset)) = self.grab(state, bounds, cursor) else {
+                        return;
                     };
 
-                    if let Some(on_select) = on_select {
-                        *pressed = Some(new_pressed);
+                    if self.is_primary(new_pressed) {
+                        let click = mouse::Click::new(cursor, *mouse_button, state.last_click);
+                        state.last_click = Some(click);
 
-                        let new_color = fetch_hsv(self.spectrum, *current_color, bounds, cursor);
-                        shell.publish((on_select)(new_color))
+                        if let mouse::click::Kind::Double = click.kind()
+                            && self.reset(shell, index)
+                        {
+                            return;
+                        }
                     }
+
+                    state.pressed = Some(new_pressed);
+                    state.grabbed = index;
+                    state.harmony_offset = harmony_offset;
+
+                    self.pick(shell, state, bounds, cursor);
                 }
                 mouse::Event::CursorMoved { .. } => {
                     if let Some(cursor) = cursor.position()
-                        && let Some(cursor_down) = pressed
+                        && let Some(Pressed::Mouse(_)) = state.pressed
                     {
-                        let new_color = fetch_hsv(self.spectrum, *current_color, bounds, cursor);
-
-                        match cursor_down {
-                            Pressed::Primary => shell.publish((self.on_select)(new_color)),
-                            Pressed::Secondary => {
-                                if let Some(on_select_alt) = &self.on_select_alt {
-                                    shell.publish(on_select_alt(new_color))
-                                }
-                            }
-                            _ => (),
-                        };
+                        self.pick(shell, state, bounds, cursor);
                     }
                 }
                 _ => (),
             },
             iced_core::Event::Touch(touch_event) => match touch_event {
-                touch::Event::FingerPressed { id, position } => {
-                    if bounds.contains(*position) && pressed.is_none() {
-                        *pressed = Some(Pressed::Finger(id.0));
+                touch::Event::FingerPressed { id, position }
+                    if bounds.contains(*position) && state.pressed.is_none() =>
+                {
+                    if let Some((index, harmony_offset)) = self.grab(state, bounds, *position) {
+                        state.pressed = Some(Pressed::Finger(id.0));
+                        state.grabbed = index;
+                        state.harmony_offset = harmony_offset;
+
+                        self.pick(shell, state, bounds, *position);
 
-                        let new_color = fetch_hsv(self.spectrum, *current_color, bounds, *position);
-                        shell.publish((self.on_select)(new_color));
+                        if self.default_color.is_some() {
+                            let now = Instant::now();
+
+                            state.long_press = Some((now, *position));
+                            shell.request_redraw_at(now + LONG_PRESS);
+                        }
                     }
                 }
                 touch::Event::FingerMoved { id, position } => {
-                    if let Some(Pressed::Finger(finger_id)) = *pressed
+                    if let Some(Pressed::Finger(finger_id)) = state.pressed
                         && id.0 == finger_id
                     {
-                        let new_color = fetch_hsv(self.spectrum, *current_color, bounds, *position);
-                        shell.publish((self.on_select)(new_color));
+                        if let Some((_, start)) = state.long_press
+                            && start.distance(*position) > TOUCH_SLOP
+                        {
+                            state.long_press = None;
+                        }
+
+                        self.pick(shell, state, bounds, *position);
                     }
                 }
-                touch::Event::FingerLifted { id, .. } => {
-                    if let Some(Pressed::Finger(finger_id)) = *pressed
+                touch::Event::FingerLifted { id, .. } | touch::Event::FingerLost { id, .. } => {
+                    if let Some(Pressed::Finger(finger_id)) = state.pressed
                         && id.0 == finger_id
                     {
-                        *pressed = None;
+                        state.pressed = None;
+                        state.long_press = None;
+
+                        // Hide the readout.
+                        if self.readout.is_some() {
+                            shell.request_redraw();
+                        }
                     }
                 }
                 _ => (),
             },
+            iced_core::Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = *modifiers;
+            }
+            iced_core::Event::Window(window::Event::RedrawRequested(now)) => {
+                if let Some((start, _)) = state.long_press {
+                    if *now < start + LONG_PRESS {
+                        shell.request_redraw_at(start + LONG_PRESS);
+                    } else if self.reset(shell, state.grabbed) {
+                        state.long_press = None;
+                        state.pressed = None;
+                    }
+                }
+            }
 
             _ => (),
         }
     }
 
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: layout::Layout<'b>,
+        _renderer: &Renderer,
+        _viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state: &State<Renderer> = tree.state.downcast_ref();
+
+        let readout = self.readout?;
+        // Only shown while dragging.
+        state.pressed?;
+        let color = *state.current_colors.get(state.grabbed)?;
+        let color = color.with_h(color.h + state.harmony_offset);
+
+        let bounds = layout.bounds();
+        let marker = marker(
+            &self.spectrum,
+            color,
+            state.gradient_offset(state.grabbed),
+            bounds.size(),
+            self.working_space,
+            self.gamut,
+            self.gamut_mapping,
+        );
+
+        Some(overlay::Element::new(Box::new(ReadoutOverlay {
+            content: readout.format(color),
+            position: bounds.position() + (marker.position - Point::ORIGIN) + translation,
+            class: &self.class,
+        })))
+    }
+
     fn draw(
         &self,
         tree: &Tree,
@@ -271,30 +918,180 @@ where
         _cursor: mouse::Cursor,
         _viewport: &iced_core::Rectangle,
     ) {
+        let state: &State<Renderer> = tree.state.downcast_ref();
         let State {
             spectrum_cache,
             marker_cache,
-            current_color,
+            current_colors,
             ..
-        }: &State<Renderer> = tree.state.downcast_ref();
+        } = state;
+
+        let current_color = current_colors.first().copied().unwrap_or_default();
 
-        let Style { marker_shape } = theme.style(&self.class);
+        let style = theme.style(&self.class);
 
         let bounds = layout.bounds();
         let size = layout.bounds().size();
 
         renderer.with_layer(bounds, |renderer| {
             renderer.with_translation(bounds.position() - Point::ORIGIN, |renderer| {
-                let spectrum = spectrum_cache.draw(renderer, size, |frame| match self.spectrum {
-                    Spectrum::SaturationValue => {
-                        spectrums::saturation_value(frame, current_color.h)
+                let space = self.working_space;
+                let gamut = self.gamut;
+                let mapping = self.gamut_mapping;
+
+                let spectrum = spectrum_cache.draw(renderer, size, |frame| {
+                    let mapping = mapping.spectrum();
+
+                    match &self.spectrum {
+                        Spectrum::SaturationValue => {
+                            spectrums::saturation_value(
+                                frame,
+                                current_color.h,
+                                space,
+                                gamut,
+                                mapping,
+                            );
+
+                            if gamut != Gamut::Srgb {
+                                spectrums::srgb_boundary(
+                                    frame,
+                                    current_color.h,
+                                    space,
+                                    gamut,
+                                    style.gamut_boundary,
+                                );
+                            }
+
+                            if let Some((background, thresholds)) = &self.contrast {
+                                let background = Hsv::from(*background);
+
+                                for &threshold in thresholds {
+                                    spectrums::saturation_value_boundary(
+                                        frame,
+                                        current_color.h,
+                                        style.contrast_boundary,
+                                        |color| {
+                                            let foreground =
+                                                Hsv::from(gamut.display(color, space, mapping));
+
+                                            contrast::contrast_ratio(foreground, background)
+                                                >= threshold
+                                        },
+                                    );
+                                }
+                            }
+                        }
+                        Spectrum::WhitenessBlackness => spectrums::whiteness_blackness(
+                            frame,
+                            current_color.h,
+                            space,
+                            gamut,
+                            mapping,
+                        ),
+                        Spectrum::HueVertical => {
+                            spectrums::hue_vertical(frame, 1.0, 1.0, space, gamut, mapping)
+                        }
+                        Spectrum::HueHorizontal => {
+                            spectrums::hue_horizontal(frame, 1.0, 1.0, space, gamut, mapping)
+                        }
+                        Spectrum::HueRing => {
+                            spectrums::hue_ring(frame, 1.0, 1.0, space, gamut, mapping)
+                        }
+                        Spectrum::Alpha(orientation) => {
+                            let color = gamut.display(
+                                Hsv {
+                                    a: 1.0,
+                                    ..current_color
+                                },
+                                space,
+                                mapping,
+                            );
+
+                            spectrums::checkerboard(
+                                frame,
+                                Rectangle::with_size(size),
+                                style.checkerboard_size,
+                                style.checkerboard_colors,
+                            );
+
+                            spectrums::alpha(frame, *orientation, color);
+                        }
+                        Spectrum::Red(orientation)
+                        | Spectrum::Green(orientation)
+                        | Spectrum::Blue(orientation)
+                        | Spectrum::Cyan(orientation)
+                        | Spectrum::Magenta(orientation)
+                        | Spectrum::Yellow(orientation)
+                        | Spectrum::Black(orientation) => {
+                            let color = Hsv {
+                                a: 1.0,
+                                ..current_color
+                            };
+
+                            spectrums::strip(frame, *orientation, |value| {
+                                let color = with_channel(&self.spectrum, color, value);
+                                gamut.display(color, space, mapping)
+                            });
+                        }
+                        Spectrum::Temperature {
+                            min_kelvin,
+                            max_kelvin,
+                            orientation,
+                        } => {
+                            let (min_kelvin, max_kelvin) = kelvin_range(*min_kelvin, *max_kelvin);
+
+                            spectrums::strip(frame, *orientation, |value| {
+                                temperature::kelvin_to_color(lerp(min_kelvin, max_kelvin, value))
+                            })
+                        }
+                        Spectrum::Gradient(gradient, orientation) => {
+                            spectrums::strip(frame, *orientation, |value| {
+                                gamut.display(gradient.sample(value), space, mapping)
+                            })
+                        }
                     }
-                    Spectrum::HueVertical => spectrums::hue_vertical(frame, 1.0, 1.0),
-                    Spectrum::HueHorizontal => spectrums::hue_horizontal(frame, 1.0, 1.0),
+
+                    draw_ticks(
+                        frame,
+                        &self.spectrum,
+                        &self.tick_values(),
+                        self.tick_labels,
+                        &style,
+                    );
                 });
 
                 let marker = marker_cache.draw(renderer, size, |frame| {
-                    marker(self.spectrum, *current_color, size).draw(frame, marker_shape);
+                    if let Some(harmony) = self.harmony
+                        && matches!(
+                            self.spectrum,
+                            Spectrum::HueHorizontal | Spectrum::HueVertical | Spectrum::HueRing
+                        )
+                    {
+                        let style = Style {
+                            marker_shape: style.secondary_marker_shape,
+                            ..style
+                        };
+
+                        for color in harmony.colors(current_color).into_iter().skip(1) {
+                            marker(&self.spectrum, color, None, size, space, gamut, mapping)
+                                .draw(frame, &style);
+                        }
+                    }
+
+                    for (index, color) in current_colors.iter().enumerate() {
+                        let gradient_offset = state.gradient_offset(index);
+
+                        marker(
+                            &self.spectrum,
+                            *color,
+                            gradient_offset,
+                            size,
+                            space,
+                            gamut,
+                            mapping,
+                        )
+                        .draw(frame, &style);
+                    }
                 });
 
                 renderer.draw_geometry(spectrum);
@@ -315,10 +1112,87 @@ where
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+/// Shows the value of the grabbed marker, outside of the bounds of the [ColorPicker].
+struct ReadoutOverlay<'a, 'b, Theme: Catalog> {
+    content: String,
+    /// The position of the marker.
+    position: Point,
+    class: &'a Theme::Class<'b>,
+}
+
+impl<Theme: Catalog> ReadoutOverlay<'_, '_, Theme> {
+    const TEXT_SIZE: f32 = 12.0;
+    const PADDING: f32 = 4.0;
+    /// The gap between the marker and the readout.
+    const OFFSET: f32 = 12.0;
+
+    /// Estimate the size of the readout, since the text can't be measured without a text renderer.
+    fn size(&self) -> Size {
+        let width = self.content.chars().count() as f32 * Self::TEXT_SIZE * 0.6;
+        let height = Self::TEXT_SIZE * 1.2;
+
+        Size::new(width + Self::PADDING * 2.0, height + Self::PADDING * 2.0)
+    }
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for ReadoutOverlay<'_, '_, Theme>
+where
+    Theme: Catalog,
+    Renderer: geometry::Renderer,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> layout::Node {
+        let size = self.size();
+        let Point { x, y } = self.position;
+
+        // Prefer the top right of the marker, flipping sides rather than covering it.
+        let x = match x + Self::OFFSET + size.width <= bounds.width {
+            true => x + Self::OFFSET,
+            false => x - Self::OFFSET - size.width,
+        };
+
+        let y = match y - Self::OFFSET - size.height >= 0.0 {
+            true => y - Self::OFFSET - size.height,
+            false => y + Self::OFFSET,
+        };
+
+        layout::Node::new(size).move_to(Point::new(x.max(0.0), y.max(0.0)))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &iced_core::renderer::Style,
+        layout: layout::Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let style = theme.style(self.class);
+        let bounds = layout.bounds();
+
+        let mut frame = Frame::new(renderer, bounds.size());
+
+        frame.fill_rectangle(Point::ORIGIN, bounds.size(), style.readout_background);
+        frame.fill_text(Text {
+            content: self.content.clone(),
+            position: Point::new(Self::PADDING, bounds.height / 2.0),
+            color: style.readout_text,
+            size: Self::TEXT_SIZE.into(),
+            align_y: alignment::Vertical::Center,
+            ..Text::default()
+        });
+
+        let readout = frame.into_geometry();
+
+        renderer.with_translation(bounds.position() - Point::ORIGIN, |renderer| {
+            renderer.draw_geometry(readout);
+        });
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Pressed {
-    Primary,
-    Secondary,
+    Mouse(mouse::Button),
     Finger(u64),
 }
 
@@ -326,7 +1200,49 @@ struct State<Renderer: geometry::Renderer> {
     spectrum_cache: geometry::Cache<Renderer>,
     marker_cache: geometry::Cache<Renderer>,
     pressed: Option<Pressed>,
-    current_color: Hsv,
+    /// The index of the marker being dragged.
+    grabbed: usize,
+    /// The hue offset of the dragged [Harmony] marker from its color, or `0.0`.
+    harmony_offset: f32,
+    modifiers: keyboard::Modifiers,
+    last_click: Option<mouse::Click>,
+    /// When and where the finger was pressed, until it moves too far to be a long press.
+    long_press: Option<(Instant, Point)>,
+    current_colors: Vec<Hsv>,
+    /// Where each marker was last picked on a [Spectrum::Gradient].
+    gradient_offsets: Vec<Option<f32>>,
+    drawn: Option<Drawn>,
+}
+
+impl<Renderer: geometry::Renderer> State<Renderer> {
+    fn gradient_offset(&self, index: usize) -> Option<f32> {
+        self.gradient_offsets.get(index).copied().flatten()
+    }
+
+    /// Remember where the grabbed marker was picked on a [Spectrum::Gradient].
+    fn set_gradient_offset(&mut self, offset: f32) {
+        if self.gradient_offsets.len() <= self.grabbed {
+            self.gradient_offsets.resize(self.grabbed + 1, None);
+        }
+
+        // The color can be the same at a different offset, so the marker is redrawn regardless.
+        if self.gradient_offsets[self.grabbed] != Some(offset) {
+            self.gradient_offsets[self.grabbed] = Some(offset);
+            self.marker_cache.clear();
+        }
+    }
+}
+
+/// The settings the caches were drawn with, other than the colors.
+#[derive(Debug, Clone, PartialEq)]
+struct Drawn {
+    spectrum: Spectrum,
+    working_space: WorkingSpace,
+    gamut: Gamut,
+    gamut_mapping: GamutMapping,
+    contrast: Option<(Color, Vec<f32>)>,
+    ticks: Vec<f32>,
+    tick_labels: bool,
 }
 
 impl<Renderer: geometry::Renderer> Default for State<Renderer> {
@@ -335,7 +1251,14 @@ impl<Renderer: geometry::Renderer> Default for State<Renderer> {
             spectrum_cache: Default::default(),
             marker_cache: Default::default(),
             pressed: Default::default(),
-            current_color: Default::default(),
+            grabbed: Default::default(),
+            harmony_offset: Default::default(),
+            modifiers: Default::default(),
+            last_click: Default::default(),
+            long_press: Default::default(),
+            current_colors: Default::default(),
+            gradient_offsets: Default::default(),
+            drawn: Default::default(),
         }
     }
 }
@@ -348,14 +1271,16 @@ struct Marker {
 }
 
 impl Marker {
-    fn draw<Renderer: geometry::Renderer>(&self, frame: &mut Frame<Renderer>, shape: MarkerShape) {
+    fn draw<Renderer: geometry::Renderer>(&self, frame: &mut Frame<Renderer>, style: &Style) {
         let Self {
             position,
             color,
             outline,
         } = *self;
 
-        match shape {
+        let translucent = color.a < 1.0;
+
+        match style.marker_shape {
             MarkerShape::Square { size, border_width } => {
                 let size = size.max(0.0);
                 let border_width = border_width.max(0.0);
@@ -369,11 +1294,18 @@ impl Marker {
                     outline,
                 );
 
-                frame.fill_rectangle(
-                    Point::new(position.x - (size / 2.0), position.y - (size / 2.0)),
-                    Size::new(size, size),
-                    color,
-                );
+                let top_left = Point::new(position.x - (size / 2.0), position.y - (size / 2.0));
+
+                if translucent {
+                    spectrums::checkerboard(
+                        frame,
+                        Rectangle::new(top_left, Size::new(size, size)),
+                        style.checkerboard_size,
+                        style.checkerboard_colors,
+                    );
+                }
+
+                frame.fill_rectangle(top_left, Size::new(size, size), color);
             }
             MarkerShape::Circle {
                 radius,
@@ -383,14 +1315,32 @@ impl Marker {
                 let border_width = border_width.max(0.0);
 
                 frame.fill(&Path::circle(position, radius + border_width), outline);
+
+                if translucent {
+                    spectrums::checkerboard_circle(
+                        frame,
+                        position,
+                        radius,
+                        style.checkerboard_size,
+                        style.checkerboard_colors,
+                    );
+                }
+
                 frame.fill(&Path::circle(position, radius), color);
             }
         }
     }
 }
 
-fn fetch_hsv(spectrum: Spectrum, current_color: Hsv, bounds: Rectangle, cursor: Point) -> Hsv {
-    match spectrum {
+fn fetch_hsv(
+    spectrum: &Spectrum,
+    current_color: Hsv,
+    bounds: Rectangle,
+    cursor: Point,
+    space: WorkingSpace,
+    gamut: Gamut,
+) -> Hsv {
+    let color = match *spectrum {
         Spectrum::SaturationValue => {
             let Vector { x, y } = cursor - bounds.position();
 
@@ -403,48 +1353,344 @@ fn fetch_hsv(spectrum: Spectrum, current_color: Hsv, bounds: Rectangle, cursor:
                 ..current_color
             }
         }
-        Spectrum::HueHorizontal => {
-            let x = cursor.x - bounds.position().x;
-            let hue = (x.max(0.0) / bounds.width).min(1.0) * 360.0;
+        Spectrum::WhitenessBlackness => {
+            let Vector { x, y } = cursor - bounds.position();
+
+            let whiteness = (x.max(0.0) / bounds.width).min(1.0);
+            let blackness = (y.max(0.0) / bounds.height).min(1.0);
+
+            Hsv::from(Hwb {
+                h: current_color.h,
+                w: whiteness,
+                b: blackness,
+                a: current_color.a,
+            })
+        }
+        Spectrum::HueHorizontal => Hsv {
+            h: strip_value(Orientation::Horizontal, bounds, cursor) * 360.0,
+            ..current_color
+        },
+        // The hue increases down the y-axis.
+        Spectrum::HueVertical => Hsv {
+            h: (1.0 - strip_value(Orientation::Vertical, bounds, cursor)) * 360.0,
+            ..current_color
+        },
+        Spectrum::HueRing => {
+            let (center, _, _) = spectrums::ring(bounds.size());
+            let Vector { x, y } = cursor - (bounds.position() + (center - Point::ORIGIN));
 
+            // The hue increases counter-clockwise, but the y-axis points down.
             Hsv {
-                h: hue,
+                h: (-y).atan2(x).to_degrees(),
                 ..current_color
             }
         }
-        Spectrum::HueVertical => {
-            let y = cursor.y - bounds.position().y;
-            let hue = (y.max(0.0) / bounds.height).min(1.0) * 360.;
+        Spectrum::Alpha(orientation) => Hsv {
+            a: strip_value(orientation, bounds, cursor),
+            ..current_color
+        },
+        Spectrum::Red(orientation)
+        | Spectrum::Green(orientation)
+        | Spectrum::Blue(orientation)
+        | Spectrum::Cyan(orientation)
+        | Spectrum::Magenta(orientation)
+        | Spectrum::Yellow(orientation)
+        | Spectrum::Black(orientation) => {
+            let value = strip_value(orientation, bounds, cursor);
+
+            with_channel(spectrum, current_color, value)
+        }
+        Spectrum::Temperature {
+            min_kelvin,
+            max_kelvin,
+            orientation,
+        } => {
+            let (min_kelvin, max_kelvin) = kelvin_range(min_kelvin, max_kelvin);
+            let value = strip_value(orientation, bounds, cursor);
+            let color = temperature::kelvin_to_color(lerp(min_kelvin, max_kelvin, value));
 
             Hsv {
-                h: hue,
-                ..current_color
+                a: current_color.a,
+                ..GamutColor::from(color).convert(gamut).to_hsv(space)
             }
         }
+        Spectrum::Gradient(ref gradient, orientation) => {
+            gradient.sample(strip_value(orientation, bounds, cursor))
+        }
+    };
+
+    color.normalized()
+}
+
+fn lerp(start: f32, end: f32, t: f32) -> f32 {
+    start + (end - start) * t
+}
+
+/// The value from `0.0` to `1.0` under the cursor on a 1-Dimensional [Spectrum].
+fn strip_value(orientation: Orientation, bounds: Rectangle, cursor: Point) -> f32 {
+    let Vector { x, y } = cursor - bounds.position();
+
+    match orientation {
+        Orientation::Horizontal => (x.max(0.0) / bounds.width).min(1.0),
+        Orientation::Vertical => 1.0 - (y.max(0.0) / bounds.height).min(1.0),
+    }
+}
+
+/// The position of the marker for a value from `0.0` to `1.0` on a 1-Dimensional [Spectrum].
+fn strip_position(orientation: Orientation, bounds: Size, value: f32) -> Point {
+    match orientation {
+        Orientation::Horizontal => Point {
+            x: value * bounds.width,
+            y: bounds.height / 2.0,
+        },
+        Orientation::Vertical => Point {
+            x: bounds.width / 2.0,
+            y: (1.0 - value) * bounds.height,
+        },
+    }
+}
+
+/// The axis of a 1-Dimensional [Spectrum].
+fn strip_orientation(spectrum: &Spectrum) -> Option<Orientation> {
+    match *spectrum {
+        Spectrum::HueHorizontal => Some(Orientation::Horizontal),
+        Spectrum::HueVertical => Some(Orientation::Vertical),
+        Spectrum::Alpha(orientation)
+        | Spectrum::Red(orientation)
+        | Spectrum::Green(orientation)
+        | Spectrum::Blue(orientation)
+        | Spectrum::Cyan(orientation)
+        | Spectrum::Magenta(orientation)
+        | Spectrum::Yellow(orientation)
+        | Spectrum::Black(orientation)
+        | Spectrum::Temperature { orientation, .. }
+        | Spectrum::Gradient(_, orientation) => Some(orientation),
+        Spectrum::SaturationValue | Spectrum::WhitenessBlackness | Spectrum::HueRing => None,
+    }
+}
+
+/// Draw ticks at values from `0.0` to `1.0` of a spectrum, and their labels if enabled.
+fn draw_ticks<Renderer: geometry::Renderer>(
+    frame: &mut Frame<Renderer>,
+    spectrum: &Spectrum,
+    values: &[f32],
+    labels: bool,
+    style: &Style,
+) {
+    if let Spectrum::HueRing = spectrum {
+        // The ring is closed, so it has a tick where it starts.
+        let hues: Vec<f32> = values.iter().map(|value| value * 360.0).collect();
+
+        spectrums::ring_ticks(frame, hues.iter().copied(), style.ticks);
+
+        if labels {
+            let labels = hues.iter().map(|&hue| (hue, format!("{hue:.0}°")));
+            spectrums::ring_tick_labels(frame, labels, style.tick_labels, style.tick_label_size);
+        }
+
+        return;
+    }
+
+    let Some(orientation) = strip_orientation(spectrum) else {
+        return;
+    };
+
+    // The hue increases down the y-axis.
+    let position = |value: f32| match spectrum {
+        Spectrum::HueVertical => 1.0 - value,
+        _ => value,
+    };
+
+    // Skip the ends of the spectrum.
+    let values: Vec<f32> = values
+        .iter()
+        .copied()
+        .filter(|value| *value > 0.0 && *value < 1.0)
+        .collect();
+
+    spectrums::ticks(
+        frame,
+        orientation,
+        values.iter().map(|&value| position(value)),
+        style.ticks,
+    );
+
+    if labels {
+        let labels = values
+            .iter()
+            .map(|&value| (position(value), tick_label(spectrum, value)));
+
+        spectrums::tick_labels(
+            frame,
+            orientation,
+            labels,
+            style.tick_labels,
+            style.tick_label_size,
+        );
+    }
+}
+
+fn tick_label(spectrum: &Spectrum, value: f32) -> String {
+    match *spectrum {
+        Spectrum::HueHorizontal | Spectrum::HueVertical => format!("{:.0}°", value * 360.0),
+        Spectrum::Temperature {
+            min_kelvin,
+            max_kelvin,
+            ..
+        } => {
+            let (min_kelvin, max_kelvin) = kelvin_range(min_kelvin, max_kelvin);
+
+            format!("{:.0}K", lerp(min_kelvin, max_kelvin, value))
+        }
+        _ => format!("{:.0}%", value * 100.0),
+    }
+}
+
+/// The range of a [Spectrum::Temperature], clamped to the temperatures supported by [temperature::kelvin_to_color].
+fn kelvin_range(min_kelvin: f32, max_kelvin: f32) -> (f32, f32) {
+    let clamp = |kelvin: f32| kelvin.clamp(temperature::MIN_KELVIN, temperature::MAX_KELVIN);
+
+    (clamp(min_kelvin), clamp(max_kelvin))
+}
+
+/// Replace the RGB or CMYK channel edited by the [Spectrum].
+fn with_channel(spectrum: &Spectrum, color: Hsv, value: f32) -> Hsv {
+    let Color { r, g, b, .. } = Color::from(color);
+    let cmyk = Cmyk::from(color);
+
+    let cmyk = match *spectrum {
+        Spectrum::Red(_) => return color.with_rgb([value, g, b]),
+        Spectrum::Green(_) => return color.with_rgb([r, value, b]),
+        Spectrum::Blue(_) => return color.with_rgb([r, g, value]),
+        Spectrum::Cyan(_) => Cmyk { c: value, ..cmyk },
+        Spectrum::Magenta(_) => Cmyk { m: value, ..cmyk },
+        Spectrum::Yellow(_) => Cmyk { y: value, ..cmyk },
+        Spectrum::Black(_) => Cmyk { k: value, ..cmyk },
+        _ => return color,
+    };
+
+    // Find the hue and saturation without the key, so they survive it being 100%.
+    let Color { r, g, b, .. } = Color::from(Cmyk { k: 0.0, ..cmyk });
+
+    Hsv {
+        v: 1.0 - cmyk.k,
+        ..color.with_rgb([r, g, b])
     }
 }
 
-fn marker(spectrum: Spectrum, current_color: Hsv, bounds: Size) -> Marker {
-    let color = match spectrum {
-        Spectrum::SaturationValue => Color::from(current_color),
-        Spectrum::HueHorizontal | Spectrum::HueVertical => {
-            Color::from(hsv(current_color.h, 1.0, 1.0))
+/// The RGB or CMYK channel edited by the [Spectrum].
+fn channel(spectrum: &Spectrum, color: Hsv) -> f32 {
+    let Color { r, g, b, .. } = Color::from(color);
+    let cmyk = Cmyk::from(color);
+
+    match *spectrum {
+        Spectrum::Red(_) => r,
+        Spectrum::Green(_) => g,
+        Spectrum::Blue(_) => b,
+        Spectrum::Cyan(_) => cmyk.c,
+        Spectrum::Magenta(_) => cmyk.m,
+        Spectrum::Yellow(_) => cmyk.y,
+        Spectrum::Black(_) => cmyk.k,
+        _ => 0.0,
+    }
+}
+
+/// `gradient_offset` is where the color was last picked on a [Spectrum::Gradient], if it was.
+fn marker(
+    spectrum: &Spectrum,
+    current_color: Hsv,
+    gradient_offset: Option<f32>,
+    bounds: Size,
+    space: WorkingSpace,
+    gamut: Gamut,
+    mapping: GamutMapping,
+) -> Marker {
+    let color = match *spectrum {
+        Spectrum::SaturationValue | Spectrum::WhitenessBlackness => {
+            gamut.display(current_color, space, mapping)
         }
+        Spectrum::HueHorizontal | Spectrum::HueVertical | Spectrum::HueRing => {
+            gamut.display(hsv(current_color.h, 1.0, 1.0), space, mapping)
+        }
+        Spectrum::Alpha(_)
+        | Spectrum::Red(_)
+        | Spectrum::Green(_)
+        | Spectrum::Blue(_)
+        | Spectrum::Cyan(_)
+        | Spectrum::Magenta(_)
+        | Spectrum::Yellow(_)
+        | Spectrum::Black(_)
+        | Spectrum::Temperature { .. }
+        | Spectrum::Gradient(..) => gamut.display(current_color, space, mapping),
     };
 
-    let position = match spectrum {
+    let position = match *spectrum {
         Spectrum::SaturationValue => Point {
             x: current_color.s * bounds.width,
             y: (1.0 - current_color.v) * bounds.height,
         },
-        Spectrum::HueVertical => Point {
-            x: bounds.width / 2.0,
-            y: (current_color.h / 360.) * bounds.height,
-        },
-        Spectrum::HueHorizontal => Point {
-            x: (current_color.h / 360.) * bounds.width,
-            y: bounds.height / 2.0,
-        },
+        Spectrum::WhitenessBlackness => {
+            // Always lies on or before the diagonal.
+            let Hwb { w, b, .. } = Hwb::from(current_color).normalized();
+
+            Point {
+                x: w * bounds.width,
+                y: b * bounds.height,
+            }
+        }
+        Spectrum::HueVertical => {
+            strip_position(Orientation::Vertical, bounds, 1.0 - current_color.h / 360.)
+        }
+        Spectrum::HueHorizontal => {
+            strip_position(Orientation::Horizontal, bounds, current_color.h / 360.)
+        }
+        Spectrum::HueRing => {
+            let (center, inner, outer) = spectrums::ring(bounds);
+            let radius = (inner + outer) / 2.0;
+            let (sin, cos) = current_color.h.to_radians().sin_cos();
+
+            Point {
+                x: center.x + radius * cos,
+                y: center.y - radius * sin,
+            }
+        }
+        Spectrum::Alpha(orientation) => strip_position(orientation, bounds, current_color.a),
+        Spectrum::Red(orientation)
+        | Spectrum::Green(orientation)
+        | Spectrum::Blue(orientation)
+        | Spectrum::Cyan(orientation)
+        | Spectrum::Magenta(orientation)
+        | Spectrum::Yellow(orientation)
+        | Spectrum::Black(orientation) => {
+            strip_position(orientation, bounds, channel(spectrum, current_color))
+        }
+        Spectrum::Temperature {
+            min_kelvin,
+            max_kelvin,
+            orientation,
+        } => {
+            let (min_kelvin, max_kelvin) = kelvin_range(min_kelvin, max_kelvin);
+            let kelvin = temperature::color_to_kelvin(color);
+            let range = max_kelvin - min_kelvin;
+
+            // A spectrum of a single temperature has nothing to divide.
+            let value = match range != 0.0 {
+                true => ((kelvin - min_kelvin) / range).clamp(0.0, 1.0),
+                false => 0.0,
+            };
+
+            strip_position(orientation, bounds, value)
+        }
+        Spectrum::Gradient(ref gradient, orientation) => {
+            // The gradient can pass through the same color more than once.
+            let offset = match gradient_offset {
+                Some(offset) if gradient.sample(offset) == current_color => offset,
+                Some(offset) => gradient.offset_near(current_color, offset),
+                None => gradient.offset_of(current_color),
+            };
+
+            strip_position(orientation, bounds, offset)
+        }
     };
 
     let outline = match color.relative_luminance() > 0.5 {
@@ -460,7 +1706,62 @@ fn marker(spectrum: Spectrum, current_color: Hsv, bounds: Size) -> Marker {
 }
 
 fn diff<Renderer>(
-    spectrum: Spectrum,
+    spectrum: &Spectrum,
+    canvas_cache: &geometry::Cache<Renderer>,
+    cursor_cache: &geometry::Cache<Renderer>,
+    current_colors: &mut Vec<Hsv>,
+    new_colors: &[Hsv],
+) -> bool
+where
+    Renderer: geometry::Renderer,
+{
+    let mut redraw = false;
+
+    if current_colors.len() != new_colors.len() {
+        current_colors.resize(new_colors.len(), Hsv::default());
+        cursor_cache.clear();
+        redraw = true;
+    }
+
+    for (index, (current_color, new_color)) in current_colors.iter_mut().zip(new_colors).enumerate()
+    {
+        // The spectrum is only drawn for the first color.
+        redraw |= match index {
+            0 => diff_color(
+                spectrum,
+                canvas_cache,
+                cursor_cache,
+                current_color,
+                *new_color,
+            ),
+            _ => diff_marker(cursor_cache, current_color, *new_color),
+        };
+    }
+
+    redraw
+}
+
+fn diff_marker<Renderer>(
+    cursor_cache: &geometry::Cache<Renderer>,
+    current_color: &mut Hsv,
+    new_color: Hsv,
+) -> bool
+where
+    Renderer: geometry::Renderer,
+{
+    let new_color = new_color.normalized();
+
+    if new_color != *current_color {
+        *current_color = new_color;
+        cursor_cache.clear();
+        return true;
+    }
+
+    false
+}
+
+fn diff_color<Renderer>(
+    spectrum: &Spectrum,
     canvas_cache: &geometry::Cache<Renderer>,
     cursor_cache: &geometry::Cache<Renderer>,
     current_color: &mut Hsv,
@@ -469,10 +1770,11 @@ fn diff<Renderer>(
 where
     Renderer: geometry::Renderer,
 {
+    let new_color = new_color.normalized();
     let mut redraw = false;
 
-    match spectrum {
-        Spectrum::SaturationValue => {
+    match *spectrum {
+        Spectrum::SaturationValue | Spectrum::WhitenessBlackness => {
             if new_color.h != current_color.h {
                 current_color.h = new_color.h;
                 canvas_cache.clear();
@@ -486,8 +1788,33 @@ where
                 cursor_cache.clear();
                 redraw = true;
             }
+
+            if new_color.a != current_color.a {
+                current_color.a = new_color.a;
+                cursor_cache.clear();
+                redraw = true;
+            }
         }
-        Spectrum::HueVertical | Spectrum::HueHorizontal => {
+        Spectrum::Temperature { .. } | Spectrum::Gradient(..) => {
+            if new_color.h != current_color.h
+                || new_color.s != current_color.s
+                || new_color.v != current_color.v
+            {
+                current_color.h = new_color.h;
+                current_color.s = new_color.s;
+                current_color.v = new_color.v;
+                cursor_cache.clear();
+                redraw = true;
+            }
+
+            // The marker is filled with the translucent color.
+            if new_color.a != current_color.a {
+                current_color.a = new_color.a;
+                cursor_cache.clear();
+                redraw = true;
+            }
+        }
+        Spectrum::HueVertical | Spectrum::HueHorizontal | Spectrum::HueRing => {
             if new_color.h != current_color.h {
                 current_color.h = new_color.h;
                 cursor_cache.clear();
@@ -498,6 +1825,34 @@ where
                 current_color.s = new_color.s;
                 current_color.v = new_color.v;
             }
+
+            current_color.a = new_color.a;
+        }
+        Spectrum::Alpha(_)
+        | Spectrum::Red(_)
+        | Spectrum::Green(_)
+        | Spectrum::Blue(_)
+        | Spectrum::Cyan(_)
+        | Spectrum::Magenta(_)
+        | Spectrum::Yellow(_)
+        | Spectrum::Black(_) => {
+            if new_color.h != current_color.h
+                || new_color.s != current_color.s
+                || new_color.v != current_color.v
+            {
+                current_color.h = new_color.h;
+                current_color.s = new_color.s;
+                current_color.v = new_color.v;
+                canvas_cache.clear();
+                cursor_cache.clear();
+                redraw = true;
+            }
+
+            if new_color.a != current_color.a {
+                current_color.a = new_color.a;
+                cursor_cache.clear();
+                redraw = true;
+            }
         }
     }
 
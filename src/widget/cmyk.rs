@@ -0,0 +1,125 @@
+//! A naive Cyan, Magenta, Yellow, Key (Black) color model.
+//!
+//! This doesn't use an ICC profile, so it should only be used as a rough guide for print colors.
+
+use super::hsv::Hsv;
+use super::oklab::{Oklab, Oklch};
+
+use iced_core::Color;
+
+/// Cyan, Magenta, Yellow, Key (Black)
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cmyk {
+    /// The cyan component.
+    pub c: f32,
+    /// The magenta component.
+    pub m: f32,
+    /// The yellow component.
+    pub y: f32,
+    /// The key (black) component.
+    pub k: f32,
+    /// The alpha component.
+    pub a: f32,
+}
+
+impl Default for Cmyk {
+    fn default() -> Self {
+        Self {
+            c: Default::default(),
+            m: Default::default(),
+            y: Default::default(),
+            k: 1.0,
+            a: 1.0,
+        }
+    }
+}
+
+pub fn cmyk(cyan: f32, magenta: f32, yellow: f32, key: f32) -> Cmyk {
+    Cmyk {
+        c: cyan,
+        m: magenta,
+        y: yellow,
+        k: key,
+        a: 1.0,
+    }
+}
+
+impl From<Cmyk> for Color {
+    fn from(Cmyk { c, m, y, k, a }: Cmyk) -> Self {
+        let white = 1.0 - k.clamp(0.0, 1.0);
+
+        Self::from_rgba(
+            (1.0 - c.clamp(0.0, 1.0)) * white,
+            (1.0 - m.clamp(0.0, 1.0)) * white,
+            (1.0 - y.clamp(0.0, 1.0)) * white,
+            a.clamp(0.0, 1.0),
+        )
+    }
+}
+
+impl From<Color> for Cmyk {
+    fn from(Color { r, g, b, a }: Color) -> Self {
+        let k = 1.0 - r.max(g.max(b));
+
+        if k >= 1.0 {
+            return Self {
+                a,
+                ..Default::default()
+            };
+        }
+
+        Self {
+            c: (1.0 - r - k) / (1.0 - k),
+            m: (1.0 - g - k) / (1.0 - k),
+            y: (1.0 - b - k) / (1.0 - k),
+            k,
+            a,
+        }
+    }
+}
+
+impl From<Hsv> for Cmyk {
+    /// Unlike converting from [Color], this keeps the cyan, magenta and yellow components of black.
+    fn from(hsv: Hsv) -> Self {
+        let Color { r, g, b, a } = Color::from(Hsv { v: 1.0, ..hsv });
+
+        Self {
+            c: 1.0 - r,
+            m: 1.0 - g,
+            y: 1.0 - b,
+            k: 1.0 - hsv.v.clamp(0.0, 1.0),
+            a,
+        }
+    }
+}
+
+impl From<Cmyk> for Hsv {
+    fn from(cmyk: Cmyk) -> Self {
+        Self::from(Color::from(cmyk))
+    }
+}
+
+impl From<Cmyk> for Oklab {
+    fn from(cmyk: Cmyk) -> Self {
+        Self::from(Color::from(cmyk))
+    }
+}
+
+impl From<Oklab> for Cmyk {
+    fn from(oklab: Oklab) -> Self {
+        Self::from(Color::from(oklab))
+    }
+}
+
+impl From<Cmyk> for Oklch {
+    fn from(cmyk: Cmyk) -> Self {
+        Self::from(Color::from(cmyk))
+    }
+}
+
+impl From<Oklch> for Cmyk {
+    fn from(oklch: Oklch) -> Self {
+        Self::from(Color::from(oklch))
+    }
+}
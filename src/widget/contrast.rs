@@ -0,0 +1,140 @@
+//! Contrast between foreground and background colors, for accessibility checks.
+//!
+//! Translucent foregrounds are blended over the background first. The alpha of the background is ignored.
+
+use super::hsv::Hsv;
+
+use iced_core::Color;
+
+/// The minimum [contrast_ratio] of normal text for WCAG level AA.
+pub const AA: f32 = 4.5;
+
+/// The minimum [contrast_ratio] of large text and graphics for WCAG level AA.
+pub const AA_LARGE: f32 = 3.0;
+
+/// The minimum [contrast_ratio] of normal text for WCAG level AAA.
+pub const AAA: f32 = 7.0;
+
+/// The minimum [contrast_ratio] of large text for WCAG level AAA.
+pub const AAA_LARGE: f32 = 4.5;
+
+/// The relative luminance of a color, from `0.0` to `1.0`, as defined by WCAG 2.x.
+///
+/// This is the same as [Color::relative_luminance]. The alpha is ignored.
+pub fn relative_luminance(color: Hsv) -> f32 {
+    Color::from(color).relative_luminance()
+}
+
+/// The WCAG 2.x contrast ratio, from `1.0` to `21.0`.
+// https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+pub fn contrast_ratio(foreground: Hsv, background: Hsv) -> f32 {
+    let background = opaque(background);
+    let foreground = blend(foreground, background);
+
+    foreground.relative_contrast(background)
+}
+
+/// Whether the [contrast_ratio] of the colors is at least the given threshold, such as [AA].
+pub fn is_readable(foreground: Hsv, background: Hsv, threshold: f32) -> bool {
+    contrast_ratio(foreground, background) >= threshold
+}
+
+/// The APCA lightness contrast (Lc), roughly from `-108.0` to `106.0`.
+///
+/// Unlike [contrast_ratio], this depends on which color is the text.
+/// Dark text on a light background is positive, and light text on a dark background is negative.
+// https://github.com/Myndex/SAPC-APCA (0.0.98G-4g)
+pub fn apca_contrast(text: Hsv, background: Hsv) -> f32 {
+    let background = opaque(background);
+    let text = blend(text, background);
+
+    let (text, background) = (apca_luminance(text), apca_luminance(background));
+
+    if (background - text).abs() < 0.0005 {
+        return 0.0;
+    }
+
+    let contrast = match background > text {
+        true => {
+            let sapc = (background.powf(0.56) - text.powf(0.57)) * 1.14;
+
+            match sapc < 0.1 {
+                true => 0.0,
+                false => sapc - 0.027,
+            }
+        }
+        false => {
+            let sapc = (background.powf(0.65) - text.powf(0.62)) * 1.14;
+
+            match sapc > -0.1 {
+                true => 0.0,
+                false => sapc + 0.027,
+            }
+        }
+    };
+
+    contrast * 100.0
+}
+
+/// Pick black or white, whichever has the higher [contrast_ratio] against the background.
+pub fn readable_foreground(background: Hsv) -> Hsv {
+    const BLACK: Hsv = Hsv {
+        h: 0.0,
+        s: 0.0,
+        v: 0.0,
+        a: 1.0,
+    };
+
+    const WHITE: Hsv = Hsv { v: 1.0, ..BLACK };
+
+    most_readable(background, [BLACK, WHITE]).unwrap_or(BLACK)
+}
+
+/// Pick the candidate with the highest [contrast_ratio] against the background.
+///
+/// Returns [None] if there are no candidates. Ties go to the earliest candidate.
+pub fn most_readable<C: Into<Hsv>>(
+    background: Hsv,
+    candidates: impl IntoIterator<Item = C>,
+) -> Option<Hsv> {
+    candidates
+        .into_iter()
+        .map(Into::into)
+        .map(|candidate| (candidate, contrast_ratio(candidate, background)))
+        .fold(
+            None,
+            |best: Option<(Hsv, f32)>, (candidate, ratio)| match best {
+                Some((_, best_ratio)) if best_ratio >= ratio => best,
+                _ => Some((candidate, ratio)),
+            },
+        )
+        .map(|(candidate, _)| candidate)
+}
+
+fn opaque(color: Hsv) -> Color {
+    Color {
+        a: 1.0,
+        ..Color::from(color)
+    }
+}
+
+fn blend(foreground: Hsv, background: Color) -> Color {
+    let foreground = Color::from(foreground);
+    let mix = |f: f32, b: f32| f * foreground.a + b * (1.0 - foreground.a);
+
+    Color::from_rgb(
+        mix(foreground.r, background.r),
+        mix(foreground.g, background.g),
+        mix(foreground.b, background.b),
+    )
+}
+
+fn apca_luminance(Color { r, g, b, .. }: Color) -> f32 {
+    let y = 0.212_672_9 * r.powf(2.4) + 0.715_152_2 * g.powf(2.4) + 0.072_175 * b.powf(2.4);
+
+    // Soft clamp near black.
+    match y < 0.022 {
+        true => y + (0.022 - y).powf(1.414),
+        false => y,
+    }
+}
@@ -0,0 +1,184 @@
+//! Color difference (deltaE) metrics.
+//!
+//! Each function accepts anything that converts into [Lab] or [Oklab], such as [Hsv](super::Hsv) and [Color](iced_core::Color).
+//! A difference of roughly `1.0` (or `0.02` for [oklab]) is the smallest a person can notice.
+
+use super::lab::{Lab, Lch};
+use super::oklab::Oklab;
+
+/// The CIE 1976 color difference, the euclidean distance in [Lab].
+pub fn cie76(reference: impl Into<Lab>, sample: impl Into<Lab>) -> f32 {
+    let (a, b) = (reference.into(), sample.into());
+
+    ((a.l - b.l).powi(2) + (a.a - b.a).powi(2) + (a.b - b.b).powi(2)).sqrt()
+}
+
+/// The CIE 1994 color difference, using the weights for graphic arts.
+///
+/// This isn't symmetric, the chroma of the reference is used to weigh the difference.
+// http://www.brucelindbloom.com/index.html?Eqn_DeltaE_CIE94.html
+pub fn cie94(reference: impl Into<Lab>, sample: impl Into<Lab>) -> f32 {
+    const K1: f32 = 0.045;
+    const K2: f32 = 0.015;
+
+    let (a, b) = (reference.into(), sample.into());
+    let (c1, c2) = (Lch::from(a).c, Lch::from(b).c);
+
+    let delta_l = a.l - b.l;
+    let delta_c = c1 - c2;
+    let delta_h2 = ((a.a - b.a).powi(2) + (a.b - b.b).powi(2) - delta_c.powi(2)).max(0.0);
+
+    let sc = 1.0 + K1 * c1;
+    let sh = 1.0 + K2 * c1;
+
+    (delta_l.powi(2) + (delta_c / sc).powi(2) + delta_h2 / sh.powi(2)).sqrt()
+}
+
+/// The CIEDE2000 color difference.
+///
+/// This is computed in double precision, matching the reference implementation to 4 decimal places.
+///
+/// ```
+/// use iced_color_picker::{Lab, delta_e};
+///
+/// // Reference pairs from "The CIEDE2000 Color-Difference Formula" by Sharma et al.
+/// let pairs = [
+///     (Lab::new(50.0, 2.6772, -79.7751, 1.0), Lab::new(50.0, 0.0, -82.7485, 1.0), 2.0425),
+///     (Lab::new(50.0, 0.0, 0.0, 1.0), Lab::new(50.0, -1.0, 2.0, 1.0), 2.3669),
+///     (Lab::new(50.0, 2.5, 0.0, 1.0), Lab::new(73.0, 25.0, -18.0, 1.0), 27.1492),
+///     (Lab::new(50.0, 2.5, 0.0, 1.0), Lab::new(50.0, 0.0, -2.5, 1.0), 4.3065),
+///     (Lab::new(60.2574, -34.0099, 36.2677, 1.0), Lab::new(60.4626, -34.1751, 39.4387, 1.0), 1.2644),
+///     (Lab::new(2.0776, 0.0795, -1.1350, 1.0), Lab::new(0.9033, -0.0636, -0.5514, 1.0), 0.9082),
+/// ];
+///
+/// for (a, b, expected) in pairs {
+///     assert!((delta_e::ciede2000(a, b) - expected).abs() < 1e-4);
+///     assert!((delta_e::ciede2000(b, a) - expected).abs() < 1e-4);
+/// }
+/// ```
+// https://hajim.rochester.edu/ece/sites/gsharma/ciede2000/
+pub fn ciede2000(reference: impl Into<Lab>, sample: impl Into<Lab>) -> f32 {
+    let (a, b) = (reference.into(), sample.into());
+    let (l1, a1, b1) = (a.l as f64, a.a as f64, a.b as f64);
+    let (l2, a2, b2) = (b.l as f64, b.a as f64, b.b as f64);
+
+    const POW25_7: f64 = 6_103_515_625.0;
+
+    let c_bar = ((a1.hypot(b1) + a2.hypot(b2)) / 2.0).powi(7);
+    let g = 0.5 * (1.0 - (c_bar / (c_bar + POW25_7)).sqrt());
+
+    let (a1, a2) = (a1 * (1.0 + g), a2 * (1.0 + g));
+    let (c1, c2) = (a1.hypot(b1), a2.hypot(b2));
+
+    let hue = |a: f64, b: f64| match a == 0.0 && b == 0.0 {
+        true => 0.0,
+        false => b.atan2(a).to_degrees().rem_euclid(360.0),
+    };
+
+    let (h1, h2) = (hue(a1, b1), hue(a2, b2));
+    let chromatic = c1 * c2 != 0.0;
+
+    let delta_l = l2 - l1;
+    let delta_c = c2 - c1;
+    let delta_h = match (chromatic, h2 - h1) {
+        (false, _) => 0.0,
+        (true, d) if d > 180.0 => d - 360.0,
+        (true, d) if d < -180.0 => d + 360.0,
+        (true, d) => d,
+    };
+    let delta_h = 2.0 * (c1 * c2).sqrt() * (delta_h / 2.0).to_radians().sin();
+
+    let l_bar = (l1 + l2) / 2.0;
+    let c_bar = (c1 + c2) / 2.0;
+    let h_bar = match (chromatic, (h1 - h2).abs() <= 180.0, h1 + h2 < 360.0) {
+        (false, _, _) => h1 + h2,
+        (true, true, _) => (h1 + h2) / 2.0,
+        (true, false, true) => (h1 + h2 + 360.0) / 2.0,
+        (true, false, false) => (h1 + h2 - 360.0) / 2.0,
+    };
+
+    let cos = |degrees: f64| degrees.to_radians().cos();
+
+    let t =
+        1.0 - 0.17 * cos(h_bar - 30.0) + 0.24 * cos(2.0 * h_bar) + 0.32 * cos(3.0 * h_bar + 6.0)
+            - 0.20 * cos(4.0 * h_bar - 63.0);
+
+    let delta_theta = 30.0 * (-((h_bar - 275.0) / 25.0).powi(2)).exp();
+    let rc = 2.0 * (c_bar.powi(7) / (c_bar.powi(7) + POW25_7)).sqrt();
+
+    let sl = 1.0 + (0.015 * (l_bar - 50.0).powi(2)) / (20.0 + (l_bar - 50.0).powi(2)).sqrt();
+    let sc = 1.0 + 0.045 * c_bar;
+    let sh = 1.0 + 0.015 * c_bar * t;
+    let rt = -(2.0 * delta_theta).to_radians().sin() * rc;
+
+    let (l, c, h) = (delta_l / sl, delta_c / sc, delta_h / sh);
+
+    (l * l + c * c + h * h + rt * c * h).sqrt() as f32
+}
+
+/// The euclidean distance in [Oklab], also known as deltaEOK.
+pub fn oklab(reference: impl Into<Oklab>, sample: impl Into<Oklab>) -> f32 {
+    reference.into().distance(sample.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use iced_core::Color;
+
+    // https://github.com/colour-science/colour/blob/develop/colour/difference/tests/test_delta_e.py
+    const REFERENCE: Lab = Lab::new(100.0, 21.572_104, 272.228_2, 1.0);
+    const SAMPLE: Lab = Lab::new(100.0, 426.679_45, 72.395_91, 1.0);
+
+    // https://www.w3.org/TR/css-color-4/#specifying-oklab-oklch
+    const RED: Oklab = Oklab::new(0.627_96, 0.224_86, 0.125_85, 1.0);
+    const BLUE: Oklab = Oklab::new(0.452_01, -0.032_46, -0.311_53, 1.0);
+
+    #[test]
+    fn cie76_reference() {
+        assert!((cie76(REFERENCE, SAMPLE) - 451.713_3).abs() < 1e-3);
+        assert!((cie76(SAMPLE, REFERENCE) - 451.713_3).abs() < 1e-3);
+    }
+
+    #[test]
+    fn cie94_reference() {
+        assert!((cie94(REFERENCE, SAMPLE) - 83.779_23).abs() < 1e-3);
+    }
+
+    #[test]
+    fn oklab_reference() {
+        assert!((oklab(RED, BLUE) - 0.537_09).abs() < 1e-4);
+        assert!(
+            (oklab(
+                Color::from_rgb(1.0, 0.0, 0.0),
+                Color::from_rgb(0.0, 0.0, 1.0)
+            ) - 0.537_09)
+                .abs()
+                < 1e-4
+        );
+        assert!((oklab(Color::WHITE, Color::BLACK) - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn mixed_color_types() {
+        use crate::widget::{Cmyk, Hwb, Oklch};
+
+        let red = Color::from_rgb(1.0, 0.0, 0.0);
+        let cmyk = Cmyk::from(red);
+        let hwb = Hwb::from(red);
+
+        assert!(cie76(cmyk, hwb) < 1e-3);
+        assert!(ciede2000(Oklch::from(red), Lch::from(red)) < 1e-3);
+        assert!(oklab(Lch::from(red), cmyk) < 1e-4);
+        assert!(oklab(hwb, Lab::from(red)) < 1e-4);
+    }
+
+    #[test]
+    fn identical() {
+        assert_eq!(cie76(REFERENCE, REFERENCE), 0.0);
+        assert_eq!(cie94(REFERENCE, REFERENCE), 0.0);
+        assert_eq!(ciede2000(REFERENCE, REFERENCE), 0.0);
+        assert_eq!(oklab(RED, RED), 0.0);
+    }
+}
@@ -0,0 +1,540 @@
+//! Wide gamut RGB color spaces.
+//!
+//! Matrices and transfer functions are taken from: <https://www.w3.org/TR/css-color-4/#color-conversion-code>
+
+use super::hsv::{Hsv, WorkingSpace};
+use super::oklab::{Oklab, Oklch};
+
+use iced_core::Color;
+
+/// The primaries and transfer function of an RGB color space.
+///
+/// All gamuts share a D65 white point.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Gamut {
+    /// The sRGB color space, used by [Color].
+    #[default]
+    Srgb,
+    /// The Display P3 color space. Uses the sRGB transfer function.
+    DisplayP3,
+    /// The ITU-R BT.2020 color space.
+    Rec2020,
+}
+
+impl Gamut {
+    /// Convert a gamma-encoded channel into linear light.
+    pub fn decode(self, value: f32) -> f32 {
+        let sign = value.signum();
+        let value = value.abs();
+
+        let linear = match self {
+            Gamut::Srgb | Gamut::DisplayP3 => match value <= 0.04045 {
+                true => value / 12.92,
+                false => ((value + 0.055) / 1.055).powf(2.4),
+            },
+            Gamut::Rec2020 => match value < REC2020_BETA * 4.5 {
+                true => value / 4.5,
+                false => ((value + REC2020_ALPHA - 1.0) / REC2020_ALPHA).powf(1.0 / 0.45),
+            },
+        };
+
+        sign * linear
+    }
+
+    /// Gamma-encode a linear light channel.
+    pub fn encode(self, value: f32) -> f32 {
+        let sign = value.signum();
+        let value = value.abs();
+
+        let encoded = match self {
+            Gamut::Srgb | Gamut::DisplayP3 => match value <= 0.0031308 {
+                true => value * 12.92,
+                false => 1.055 * value.powf(1.0 / 2.4) - 0.055,
+            },
+            Gamut::Rec2020 => match value < REC2020_BETA {
+                true => value * 4.5,
+                false => REC2020_ALPHA * value.powf(0.45) - (REC2020_ALPHA - 1.0),
+            },
+        };
+
+        sign * encoded
+    }
+
+    /// Matrix converting linear RGB into CIE XYZ.
+    pub(crate) fn rgb_to_xyz(self) -> &'static Matrix {
+        match self {
+            Gamut::Srgb => &SRGB_TO_XYZ,
+            Gamut::DisplayP3 => &P3_TO_XYZ,
+            Gamut::Rec2020 => &REC2020_TO_XYZ,
+        }
+    }
+
+    /// Matrix converting CIE XYZ into linear RGB.
+    pub(crate) fn xyz_to_rgb(self) -> &'static Matrix {
+        match self {
+            Gamut::Srgb => &XYZ_TO_SRGB,
+            Gamut::DisplayP3 => &XYZ_TO_P3,
+            Gamut::Rec2020 => &XYZ_TO_REC2020,
+        }
+    }
+
+    /// Produce the sRGB [Color] used to display an [Hsv] whose components are in this [Gamut].
+    ///
+    /// Colors outside of sRGB are brought into gamut with the given [GamutMapping].
+    pub fn display(self, hsv: Hsv, space: WorkingSpace, mapping: GamutMapping) -> Color {
+        match self {
+            Gamut::Srgb => hsv.to_color(space),
+            gamut => {
+                let color = mapping.map(GamutColor::from_hsv(gamut, hsv, space), Gamut::Srgb);
+                Color::from_rgba(color.r, color.g, color.b, color.a)
+            }
+        }
+    }
+
+    /// Check if an [Hsv] whose components are in this [Gamut] can be represented in sRGB.
+    pub fn contains_in_srgb(self, hsv: Hsv, space: WorkingSpace) -> bool {
+        match self {
+            Gamut::Srgb => true,
+            gamut => GamutColor::from_hsv(gamut, hsv, space)
+                .convert(Gamut::Srgb)
+                .in_gamut(),
+        }
+    }
+}
+
+/// An RGB color tagged with the [Gamut] it belongs to.
+///
+/// The channels are encoded with the transfer function of the [Gamut],
+/// and may lie outside of `0.0..=1.0` if the color is out of gamut.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GamutColor {
+    /// The gamut of the color.
+    pub gamut: Gamut,
+    /// The red component.
+    pub r: f32,
+    /// The green component.
+    pub g: f32,
+    /// The blue component.
+    pub b: f32,
+    /// The alpha component.
+    pub a: f32,
+}
+
+impl GamutColor {
+    pub const fn new(gamut: Gamut, r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { gamut, r, g, b, a }
+    }
+
+    /// Create a [GamutColor] from linear light channels.
+    pub fn from_linear(gamut: Gamut, [r, g, b]: [f32; 3], a: f32) -> Self {
+        Self {
+            gamut,
+            r: gamut.encode(r),
+            g: gamut.encode(g),
+            b: gamut.encode(b),
+            a,
+        }
+    }
+
+    /// The linear light channels of the color.
+    pub fn to_linear(self) -> [f32; 3] {
+        [
+            self.gamut.decode(self.r),
+            self.gamut.decode(self.g),
+            self.gamut.decode(self.b),
+        ]
+    }
+
+    /// Create a [GamutColor] from an [Hsv] whose components are in the given [Gamut] and [WorkingSpace].
+    ///
+    /// Unlike [Color], this does not clip the result.
+    pub fn from_hsv(gamut: Gamut, hsv: Hsv, space: WorkingSpace) -> Self {
+        let Color { r, g, b, a } = Color::from(hsv);
+
+        match space {
+            WorkingSpace::Srgb => Self { gamut, r, g, b, a },
+            WorkingSpace::LinearSrgb => Self::from_linear(gamut, [r, g, b], a),
+        }
+    }
+
+    /// Convert to an [Hsv] whose components are in the color's [Gamut] and the given [WorkingSpace].
+    ///
+    /// The color is clipped to its gamut first.
+    pub fn to_hsv(self, space: WorkingSpace) -> Hsv {
+        let color = self.clip();
+
+        let [r, g, b] = match space {
+            WorkingSpace::Srgb => [color.r, color.g, color.b],
+            WorkingSpace::LinearSrgb => color.to_linear(),
+        };
+
+        Hsv::from(Color::from_rgba(r, g, b, color.a))
+    }
+
+    /// Convert the color into another [Gamut]. The result is not clipped.
+    pub fn convert(self, gamut: Gamut) -> Self {
+        if self.gamut == gamut {
+            return self;
+        }
+
+        let xyz = mul(self.gamut.rgb_to_xyz(), self.to_linear());
+
+        Self::from_linear(gamut, mul(gamut.xyz_to_rgb(), xyz), self.a)
+    }
+
+    /// Check if the color lies within its [Gamut].
+    pub fn in_gamut(self) -> bool {
+        const EPSILON: f32 = 1e-5;
+
+        [self.r, self.g, self.b]
+            .iter()
+            .all(|c| (-EPSILON..=1.0 + EPSILON).contains(c))
+    }
+
+    /// Clamp the channels of the color to its [Gamut].
+    ///
+    /// This is the simplest form of gamut mapping, but it can shift the hue.
+    pub fn clip(self) -> Self {
+        Self {
+            r: self.r.clamp(0.0, 1.0),
+            g: self.g.clamp(0.0, 1.0),
+            b: self.b.clamp(0.0, 1.0),
+            a: self.a.clamp(0.0, 1.0),
+            ..self
+        }
+    }
+
+    /// Convert the color into another [Gamut], using the given [GamutMapping] if it is out of gamut.
+    pub fn map(self, gamut: Gamut, mapping: GamutMapping) -> Self {
+        mapping.map(self, gamut)
+    }
+
+    /// Convert the color into sRGB, clipping it if it is out of gamut.
+    pub fn to_srgb(self) -> Color {
+        let Self { r, g, b, a, .. } = self.convert(Gamut::Srgb).clip();
+
+        Color::from_rgba(r, g, b, a)
+    }
+}
+
+/// A strategy to bring an out of gamut color into a [Gamut].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GamutMapping {
+    /// Clamp each channel. See [clip].
+    #[default]
+    Clip,
+    /// Reduce the chroma in OKLCH. See [reduce_chroma].
+    ReduceChroma,
+    /// Find the closest color, even if its hue is different. See [closest].
+    ///
+    /// This is too slow to run for every pixel, so the [ColorPicker](crate::ColorPicker)
+    /// draws its spectrum with [GamutMapping::ReduceChroma] instead.
+    Closest,
+}
+
+impl GamutMapping {
+    /// Convert a color into the given [Gamut] using this strategy.
+    pub fn map(self, color: GamutColor, gamut: Gamut) -> GamutColor {
+        match self {
+            GamutMapping::Clip => clip(color, gamut),
+            GamutMapping::ReduceChroma => reduce_chroma(color, gamut),
+            GamutMapping::Closest => closest(color, gamut),
+        }
+    }
+
+    /// The strategy used to draw spectrums, replacing [GamutMapping::Closest] with [GamutMapping::ReduceChroma].
+    pub(crate) fn spectrum(self) -> Self {
+        match self {
+            GamutMapping::Closest => GamutMapping::ReduceChroma,
+            mapping => mapping,
+        }
+    }
+}
+
+/// Convert a color into the given [Gamut], clamping each channel.
+///
+/// This is the fastest strategy, but it can noticeably shift the hue.
+pub fn clip(color: GamutColor, gamut: Gamut) -> GamutColor {
+    color.convert(gamut).clip()
+}
+
+/// Convert a color into the given [Gamut], reducing its OKLCH chroma until
+/// clipping it is no longer noticeable.
+///
+/// This is the gamut mapping algorithm recommended by CSS Color 4:
+/// <https://www.w3.org/TR/css-color-4/#binsearch>
+pub fn reduce_chroma(color: GamutColor, gamut: Gamut) -> GamutColor {
+    // Just noticeable difference in deltaEOK.
+    const JND: f32 = 0.02;
+    const EPSILON: f32 = 0.0001;
+
+    let destination = color.convert(gamut);
+
+    if destination.in_gamut() {
+        return destination;
+    }
+
+    let origin = Oklch::from(Oklab::from(color));
+
+    if origin.l >= 1.0 {
+        return GamutColor::new(gamut, 1.0, 1.0, 1.0, color.a);
+    }
+
+    if origin.l <= 0.0 {
+        return GamutColor::new(gamut, 0.0, 0.0, 0.0, color.a);
+    }
+
+    let project = |chroma: f32| {
+        Oklab::from(Oklch {
+            c: chroma,
+            ..origin
+        })
+    };
+
+    let mut clipped = destination.clip();
+
+    if project(origin.c).distance(Oklab::from(clipped)) < JND {
+        return clipped;
+    }
+
+    let mut min = 0.0;
+    let mut max = origin.c;
+    let mut min_in_gamut = true;
+
+    while max - min > EPSILON {
+        let chroma = (min + max) / 2.0;
+        let current = project(chroma);
+        let candidate = current.to_gamut(gamut);
+
+        if min_in_gamut && candidate.in_gamut() {
+            min = chroma;
+            continue;
+        }
+
+        clipped = candidate.clip();
+        let error = current.distance(Oklab::from(clipped));
+
+        if error < JND {
+            if JND - error < EPSILON {
+                break;
+            }
+
+            min_in_gamut = false;
+            min = chroma;
+        } else {
+            max = chroma;
+        }
+    }
+
+    clipped
+}
+
+/// Convert a color into the given [Gamut], finding the in gamut color that has the smallest
+/// deltaEOK from the original.
+///
+/// Unlike [reduce_chroma], the hue may shift, and the result is never further away than [clip].
+/// This is the most accurate strategy, but also the slowest.
+pub fn closest(color: GamutColor, gamut: Gamut) -> GamutColor {
+    const EPSILON: f32 = 0.0001;
+    const ITERATIONS: usize = 256;
+    const INITIAL_STEP: f32 = 0.05;
+
+    let destination = color.convert(gamut);
+
+    if destination.in_gamut() {
+        return destination;
+    }
+
+    let origin = Oklab::from(color);
+    let distance = |[r, g, b]: [f32; 3]| {
+        origin.distance(Oklab::from(GamutColor::new(gamut, r, g, b, color.a)))
+    };
+
+    // Start from the closer of clipping and keeping the hue.
+    let [clipped, same_hue] = [destination.clip(), closest_same_hue(origin, gamut)]
+        .map(|color| [color.r, color.g, color.b]);
+
+    let mut best = match distance(clipped) <= distance(same_hue) {
+        true => clipped,
+        false => same_hue,
+    };
+    let mut best_distance = distance(best);
+
+    // Then walk along the surface of the gamut, halving the step whenever no channel can improve.
+    let mut step = INITIAL_STEP;
+
+    for _ in 0..ITERATIONS {
+        if step < EPSILON {
+            break;
+        }
+
+        let mut improved = false;
+
+        for channel in 0..3 {
+            for delta in [-step, step] {
+                let mut candidate = best;
+                candidate[channel] = (candidate[channel] + delta).clamp(0.0, 1.0);
+
+                let candidate_distance = distance(candidate);
+
+                if candidate_distance < best_distance {
+                    best = candidate;
+                    best_distance = candidate_distance;
+                    improved = true;
+                }
+            }
+        }
+
+        if !improved {
+            step /= 2.0;
+        }
+    }
+
+    let [r, g, b] = best;
+
+    GamutColor::new(gamut, r, g, b, color.a)
+}
+
+/// The in gamut color with the same OKLCH hue that has the smallest deltaEOK from the origin.
+fn closest_same_hue(origin: Oklab, gamut: Gamut) -> GamutColor {
+    const EPSILON: f32 = 0.0001;
+    const ITERATIONS: usize = 24;
+
+    let lch = Oklch::from(origin);
+
+    // The most saturated in gamut color at the given lightness.
+    let boundary = |l: f32| {
+        let mut min = 0.0;
+        let mut max = lch.c;
+
+        while max - min > EPSILON {
+            let chroma = (min + max) / 2.0;
+
+            match Oklab::from(Oklch {
+                l,
+                c: chroma,
+                ..lch
+            })
+            .to_gamut(gamut)
+            .in_gamut()
+            {
+                true => min = chroma,
+                false => max = chroma,
+            }
+        }
+
+        Oklab::from(Oklch { l, c: min, ..lch })
+            .to_gamut(gamut)
+            .clip()
+    };
+
+    let distance = |l: f32| origin.distance(Oklab::from(boundary(l)));
+
+    let mut low = 0.0_f32;
+    let mut high = 1.0_f32;
+
+    for _ in 0..ITERATIONS {
+        let third = (high - low) / 3.0;
+
+        match distance(low + third) < distance(high - third) {
+            true => high -= third,
+            false => low += third,
+        }
+    }
+
+    boundary((low + high) / 2.0)
+}
+
+impl From<Color> for GamutColor {
+    fn from(Color { r, g, b, a }: Color) -> Self {
+        Self::new(Gamut::Srgb, r, g, b, a)
+    }
+}
+
+impl From<GamutColor> for Color {
+    fn from(color: GamutColor) -> Self {
+        color.to_srgb()
+    }
+}
+
+pub(crate) type Matrix = [[f32; 3]; 3];
+
+pub(crate) fn mul(m: &Matrix, [x, y, z]: [f32; 3]) -> [f32; 3] {
+    [
+        m[0][0] * x + m[0][1] * y + m[0][2] * z,
+        m[1][0] * x + m[1][1] * y + m[1][2] * z,
+        m[2][0] * x + m[2][1] * y + m[2][2] * z,
+    ]
+}
+
+const REC2020_ALPHA: f32 = 1.099_296_8;
+const REC2020_BETA: f32 = 0.018_053_97;
+
+const SRGB_TO_XYZ: Matrix = [
+    [0.412_390_8, 0.357_584_33, 0.180_480_8],
+    [0.212_639, 0.715_168_7, 0.072_192_32],
+    [0.019_330_818, 0.119_194_78, 0.950_532_14],
+];
+
+const XYZ_TO_SRGB: Matrix = [
+    [3.240_97, -1.537_383_2, -0.498_610_76],
+    [-0.969_243_65, 1.875_967_5, 0.041_555_06],
+    [0.055_630_08, -0.203_976_96, 1.056_971_5],
+];
+
+const P3_TO_XYZ: Matrix = [
+    [0.486_570_95, 0.265_667_7, 0.198_217_29],
+    [0.228_974_56, 0.691_738_5, 0.079_286_91],
+    [0.0, 0.045_113_38, 1.043_944_4],
+];
+
+const XYZ_TO_P3: Matrix = [
+    [2.493_497, -0.931_383_6, -0.402_710_78],
+    [-0.829_489, 1.762_664_1, 0.023_624_686],
+    [0.035_845_83, -0.076_172_39, 0.956_884_5],
+];
+
+const REC2020_TO_XYZ: Matrix = [
+    [0.636_958, 0.144_616_9, 0.168_880_98],
+    [0.262_700_2, 0.677_998_1, 0.059_301_716],
+    [0.0, 0.028_072_693, 1.060_985_1],
+];
+
+const XYZ_TO_REC2020: Matrix = [
+    [1.716_651_2, -0.355_670_78, -0.253_366_3],
+    [-0.666_684_3, 1.616_481_2, 0.015_768_546],
+    [0.017_639_857, -0.042_770_613, 0.942_103_1],
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn closest_is_closest() {
+        let colors = [
+            GamutColor::new(Gamut::Rec2020, 0.0, 1.0, 0.0, 1.0),
+            GamutColor::new(Gamut::Rec2020, 0.0, 0.0, 1.0, 1.0),
+            GamutColor::new(Gamut::DisplayP3, 1.0, 0.0, 0.0, 1.0),
+            GamutColor::new(Gamut::DisplayP3, 0.2, 0.9, 0.9, 1.0),
+        ];
+
+        for color in colors {
+            let origin = Oklab::from(color);
+            let distance = |mapping: GamutMapping| {
+                let mapped = mapping.map(color, Gamut::Srgb);
+                assert!(mapped.in_gamut());
+
+                origin.distance(Oklab::from(mapped))
+            };
+
+            let closest = distance(GamutMapping::Closest);
+
+            assert!(closest <= distance(GamutMapping::Clip));
+            assert!(closest <= distance(GamutMapping::ReduceChroma));
+        }
+    }
+}
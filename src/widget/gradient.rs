@@ -0,0 +1,279 @@
+//! Gradients made from color stops, displayed by [Spectrum::Gradient](super::Spectrum::Gradient).
+
+use super::hsv::Hsv;
+use super::oklab::Oklab;
+
+use iced_core::Color;
+
+use std::sync::Arc;
+
+/// The color space colors are blended in, between the stops of a [Gradient].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Interpolation {
+    /// Blend the gamma-encoded RGB components.
+    #[default]
+    Srgb,
+    /// Blend the linear light RGB components.
+    LinearSrgb,
+    /// Blend in the perceptual [Oklab] color space.
+    Oklab,
+    /// Blend the [Hsv] components, taking the shortest path around the hue circle.
+    Hsv,
+}
+
+/// A color at a given offset of a [Gradient].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColorStop {
+    /// The offset of the stop, from `0.0` to `1.0`.
+    pub offset: f32,
+    /// The color of the stop.
+    pub color: Hsv,
+}
+
+/// A list of [ColorStop]s, and how to blend between them.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "GradientData"))]
+pub struct Gradient {
+    stops: Arc<[ColorStop]>,
+    interpolation: Interpolation,
+}
+
+/// The deserialized form of a [Gradient], before its stops are clamped and sorted by [Gradient::new].
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct GradientData {
+    stops: Vec<ColorStop>,
+    interpolation: Interpolation,
+}
+
+#[cfg(feature = "serde")]
+impl From<GradientData> for Gradient {
+    fn from(data: GradientData) -> Self {
+        Self::new(
+            data.stops
+                .into_iter()
+                .map(|ColorStop { offset, color }| (offset, color)),
+            data.interpolation,
+        )
+    }
+}
+
+impl Gradient {
+    /// Create a [Gradient] from a list of offsets and colors.
+    ///
+    /// Offsets are clamped between `0.0` and `1.0`, and the stops are sorted by their offset.
+    pub fn new<C: Into<Hsv>>(
+        stops: impl IntoIterator<Item = (f32, C)>,
+        interpolation: Interpolation,
+    ) -> Self {
+        let mut stops: Vec<ColorStop> = stops
+            .into_iter()
+            .map(|(offset, color)| ColorStop {
+                offset: offset.clamp(0.0, 1.0),
+                color: color.into(),
+            })
+            .collect();
+
+        stops.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+
+        Self {
+            stops: stops.into(),
+            interpolation,
+        }
+    }
+
+    /// Create a [Gradient] from evenly spaced colors.
+    pub fn evenly_spaced<C: Into<Hsv>>(
+        colors: impl IntoIterator<Item = C>,
+        interpolation: Interpolation,
+    ) -> Self {
+        let colors: Vec<Hsv> = colors.into_iter().map(Into::into).collect();
+        let last = colors.len().saturating_sub(1).max(1) as f32;
+
+        Self::new(
+            colors
+                .into_iter()
+                .enumerate()
+                .map(|(i, color)| (i as f32 / last, color)),
+            interpolation,
+        )
+    }
+
+    /// The [ColorStop]s of the gradient, sorted by their offset.
+    pub fn stops(&self) -> &[ColorStop] {
+        &self.stops
+    }
+
+    /// The [Interpolation] of the gradient.
+    pub fn interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+
+    /// The color at the given offset, from `0.0` to `1.0`.
+    pub fn sample(&self, offset: f32) -> Hsv {
+        let (first, last) = match (self.stops.first(), self.stops.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return Hsv::default(),
+        };
+
+        if offset <= first.offset {
+            return first.color;
+        }
+
+        if offset >= last.offset {
+            return last.color;
+        }
+
+        let end = self
+            .stops
+            .iter()
+            .position(|stop| stop.offset >= offset)
+            .unwrap_or(self.stops.len() - 1);
+
+        let start = &self.stops[end.saturating_sub(1)];
+        let end = &self.stops[end];
+
+        let range = end.offset - start.offset;
+        let t = match range > 0.0 {
+            true => (offset - start.offset) / range,
+            false => 1.0,
+        };
+
+        mix(start.color, end.color, t, self.interpolation)
+    }
+
+    /// Find the offset with the closest color to the one given.
+    ///
+    /// This is approximated by sampling the gradient.
+    pub fn offset_of(&self, color: Hsv) -> f32 {
+        const SAMPLES: usize = 256;
+
+        let target = Oklab::from(color);
+
+        (0..=SAMPLES)
+            .map(|i| i as f32 / SAMPLES as f32)
+            .min_by(|a, b| {
+                let a = target.distance(Oklab::from(self.sample(*a)));
+                let b = target.distance(Oklab::from(self.sample(*b)));
+
+                a.total_cmp(&b)
+            })
+            .unwrap_or_default()
+    }
+
+    /// Find the offset with the closest color to the one given, like [Gradient::offset_of].
+    ///
+    /// When the gradient passes through the color more than once, the offset closest to `hint` is preferred.
+    pub fn offset_near(&self, color: Hsv, hint: f32) -> f32 {
+        const SAMPLES: usize = 256;
+        // Matches further than this deltaEOK from the closest one are ignored.
+        const TOLERANCE: f32 = 0.02;
+
+        let target = Oklab::from(color);
+
+        let distances: Vec<(f32, f32)> = (0..=SAMPLES)
+            .map(|i| i as f32 / SAMPLES as f32)
+            .map(|offset| (offset, target.distance(Oklab::from(self.sample(offset)))))
+            .collect();
+
+        let closest = distances
+            .iter()
+            .map(|(_, distance)| *distance)
+            .fold(f32::INFINITY, f32::min);
+
+        // Every pass through the color is a local minimum of the distance.
+        let is_match = |i: usize| {
+            let distance = distances[i].1;
+            let previous = i.checked_sub(1).and_then(|i| distances.get(i));
+            let next = distances.get(i + 1);
+
+            distance <= closest + TOLERANCE
+                && previous.is_none_or(|(_, previous)| distance <= *previous)
+                && next.is_none_or(|(_, next)| distance <= *next)
+        };
+
+        (0..distances.len())
+            .filter(|i| is_match(*i))
+            .map(|i| distances[i].0)
+            .min_by(|a, b| (a - hint).abs().total_cmp(&(b - hint).abs()))
+            .unwrap_or_default()
+    }
+}
+
+/// Blend two colors in the given [Interpolation].
+pub fn mix(start: Hsv, end: Hsv, t: f32, interpolation: Interpolation) -> Hsv {
+    let lerp = |a: f32, b: f32| a + (b - a) * t;
+
+    match interpolation {
+        Interpolation::Srgb => {
+            let (a, b) = (Color::from(start), Color::from(end));
+
+            Hsv::from(Color::from_rgba(
+                lerp(a.r, b.r),
+                lerp(a.g, b.g),
+                lerp(a.b, b.b),
+                lerp(a.a, b.a),
+            ))
+        }
+        Interpolation::LinearSrgb => {
+            let (a, b) = (
+                Color::from(start).into_linear(),
+                Color::from(end).into_linear(),
+            );
+
+            Hsv::from(Color::from_linear_rgba(
+                lerp(a[0], b[0]),
+                lerp(a[1], b[1]),
+                lerp(a[2], b[2]),
+                lerp(a[3], b[3]),
+            ))
+        }
+        Interpolation::Oklab => {
+            let (a, b) = (Oklab::from(start), Oklab::from(end));
+
+            Hsv::from(Oklab::new(
+                lerp(a.l, b.l),
+                lerp(a.a, b.a),
+                lerp(a.b, b.b),
+                lerp(a.alpha, b.alpha),
+            ))
+        }
+        Interpolation::Hsv => {
+            let delta = (end.h - start.h + 180.0).rem_euclid(360.0) - 180.0;
+
+            Hsv {
+                h: (start.h + delta * t).rem_euclid(360.0),
+                s: lerp(start.s, end.s),
+                v: lerp(start.v, end.v),
+                a: lerp(start.a, end.a),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offset_near_repeated_color() {
+        let gradient = Gradient::new(
+            [
+                (0.0, Color::BLACK),
+                (0.5, Color::WHITE),
+                (1.0, Color::BLACK),
+            ],
+            Interpolation::Srgb,
+        );
+
+        for offset in [0.1, 0.3, 0.7, 0.9] {
+            let color = gradient.sample(offset);
+
+            assert!((gradient.offset_near(color, offset + 0.05) - offset).abs() < 0.01);
+            assert!((gradient.offset_near(color, offset - 0.05) - offset).abs() < 0.01);
+        }
+    }
+}
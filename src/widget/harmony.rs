@@ -0,0 +1,45 @@
+//! Color schemes made by rotating the hue of a base color.
+
+use super::hsv::Hsv;
+
+/// A color scheme derived from the hue of a base color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Harmony {
+    /// The base color, and the color opposite to it.
+    Complementary,
+    /// Three colors evenly spaced around the hue circle.
+    Triadic,
+    /// The base color, and the colors 30° either side of it.
+    Analogous,
+    /// The base color, and the two colors either side of its complement.
+    SplitComplementary,
+    /// Two pairs of complementary colors, 60° apart.
+    Tetradic,
+    /// Four colors evenly spaced around the hue circle.
+    Square,
+}
+
+impl Harmony {
+    /// The hue offsets in degrees of each color from the base color, starting with the base color itself.
+    pub fn offsets(self) -> &'static [f32] {
+        match self {
+            Harmony::Complementary => &[0.0, 180.0],
+            Harmony::Triadic => &[0.0, 120.0, 240.0],
+            Harmony::Analogous => &[0.0, -30.0, 30.0],
+            Harmony::SplitComplementary => &[0.0, 150.0, 210.0],
+            Harmony::Tetradic => &[0.0, 60.0, 180.0, 240.0],
+            Harmony::Square => &[0.0, 90.0, 180.0, 270.0],
+        }
+    }
+
+    /// The colors of the scheme, starting with the base color.
+    ///
+    /// Only the hue changes, the saturation, value and alpha of the base color are kept.
+    pub fn colors(self, base: Hsv) -> Vec<Hsv> {
+        self.offsets()
+            .iter()
+            .map(|offset| base.with_h(base.h + offset))
+            .collect()
+    }
+}
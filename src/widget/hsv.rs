@@ -4,6 +4,7 @@ use iced_core::Color;
 
 /// Hue, Saturation, Value (Brightness)
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Hsv {
     /// The Hue component.
     pub h: f32,
@@ -15,6 +16,19 @@ pub struct Hsv {
     pub a: f32,
 }
 
+/// The RGB space in which the components of an [Hsv] are interpreted.
+///
+/// [Color] is always gamma-encoded sRGB, so this only affects how [Hsv] maps onto it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WorkingSpace {
+    /// Gamma-encoded sRGB, the same encoding used by [Color].
+    #[default]
+    Srgb,
+    /// Linear-light sRGB.
+    LinearSrgb,
+}
+
 impl Default for Hsv {
     fn default() -> Self {
         Self {
@@ -39,8 +53,39 @@ pub fn hsva(hue: f32, saturation: f32, value: f32, alpha: f32) -> Hsv {
     }
 }
 
+/// The tolerance used when comparing [Hsv] values for equality.
+///
+/// The hue is compared in turns rather than degrees, so it shares the same tolerance.
+pub const EPSILON: f32 = 1e-4;
+
+/// Wrap a hue in degrees into the range `0.0..360.0`.
+pub fn wrap_hue(hue: f32) -> f32 {
+    if !hue.is_finite() {
+        return 0.0;
+    }
+
+    let hue = hue.rem_euclid(360.0);
+
+    // `rem_euclid` can round up to 360.0 for tiny negative values.
+    if hue >= 360.0 { 0.0 } else { hue }
+}
+
+impl PartialEq for Hsv {
+    /// Compares the [normalized](Hsv::normalized) components within [EPSILON].
+    ///
+    /// Note that this means equality isn't transitive.
+    fn eq(&self, other: &Self) -> bool {
+        self.approx_eq(*other, EPSILON)
+    }
+}
+
 impl From<Hsv> for Color {
     fn from(hsv: Hsv) -> Self {
+        let hsv = Hsv {
+            h: wrap_hue(hsv.h),
+            ..hsv
+        };
+
         // https://en.wikipedia.org/wiki/HSL_and_HSV#Color_conversion_formulae
         let h = (hsv.h / 60.0).floor();
         let f = (hsv.h / 60.0) - h;
@@ -95,6 +140,111 @@ impl From<Color> for Hsv {
 }
 
 impl Hsv {
+    /// Create a new [Hsv], wrapping the hue and clamping the other components.
+    pub fn new(hue: f32, saturation: f32, value: f32, alpha: f32) -> Self {
+        hsva(hue, saturation, value, alpha).normalized()
+    }
+
+    /// Wrap the hue into `0.0..360.0`, and clamp the other components between `0.0` and `1.0`.
+    ///
+    /// `NaN` components are replaced with `0.0`, apart from alpha which is replaced with `1.0`.
+    pub fn normalized(self) -> Self {
+        let clamp = |component: f32, default: f32| match component.is_nan() {
+            true => default,
+            false => component.clamp(0.0, 1.0),
+        };
+
+        Self {
+            h: wrap_hue(self.h),
+            s: clamp(self.s, 0.0),
+            v: clamp(self.v, 0.0),
+            a: clamp(self.a, 1.0),
+        }
+    }
+
+    /// Replace the hue, wrapping it into `0.0..360.0`.
+    pub fn with_h(self, hue: f32) -> Self {
+        Self {
+            h: wrap_hue(hue),
+            ..self
+        }
+    }
+
+    /// Replace the saturation, clamping it between `0.0` and `1.0`.
+    pub fn with_s(self, saturation: f32) -> Self {
+        Self {
+            s: saturation.clamp(0.0, 1.0),
+            ..self
+        }
+    }
+
+    /// Replace the value, clamping it between `0.0` and `1.0`.
+    pub fn with_v(self, value: f32) -> Self {
+        Self {
+            v: value.clamp(0.0, 1.0),
+            ..self
+        }
+    }
+
+    /// Replace the alpha, clamping it between `0.0` and `1.0`.
+    pub fn with_alpha(self, alpha: f32) -> Self {
+        Self {
+            a: alpha.clamp(0.0, 1.0),
+            ..self
+        }
+    }
+
+    /// Compare the [normalized](Hsv::normalized) components of two colors within a tolerance.
+    ///
+    /// The hue is compared in turns, taking the shortest path around the hue circle.
+    pub fn approx_eq(self, other: Self, tolerance: f32) -> bool {
+        let (a, b) = (self.normalized(), other.normalized());
+
+        let hue = (a.h - b.h).abs() / 360.0;
+        let hue = hue.min(1.0 - hue);
+
+        hue <= tolerance
+            && (a.s - b.s).abs() <= tolerance
+            && (a.v - b.v).abs() <= tolerance
+            && (a.a - b.a).abs() <= tolerance
+    }
+
+    /// Convert to a [Color], treating the [Hsv] components as being in the given [WorkingSpace].
+    pub fn to_color(self, space: WorkingSpace) -> Color {
+        let color = Color::from(self);
+
+        match space {
+            WorkingSpace::Srgb => color,
+            WorkingSpace::LinearSrgb => Color::from_linear_rgba(color.r, color.g, color.b, color.a),
+        }
+    }
+
+    /// Convert from a [Color], producing [Hsv] components in the given [WorkingSpace].
+    pub fn from_color(color: Color, space: WorkingSpace) -> Self {
+        match space {
+            WorkingSpace::Srgb => Self::from(color),
+            WorkingSpace::LinearSrgb => {
+                let [r, g, b, a] = color.into_linear();
+                Self::from(Color::from_rgba(r, g, b, a))
+            }
+        }
+    }
+
+    /// Replace the color with the given RGB components, keeping the alpha.
+    ///
+    /// The hue is kept if the new color is grey, and the saturation is kept if it is black,
+    /// since they can't be recovered from the RGB components.
+    pub fn with_rgb(self, [r, g, b]: [f32; 3]) -> Self {
+        let new = Self::from(Color::from_rgb(r, g, b));
+
+        Self {
+            h: if new.s == 0.0 { self.h } else { new.h },
+            s: if new.v == 0.0 { self.s } else { new.s },
+            v: new.v,
+            a: self.a,
+        }
+    }
+
     pub fn from_rgba8(rgba: impl Into<[u8; 4]>) -> Self {
         let [r, g, b, a] = rgba.into();
 
@@ -134,8 +284,243 @@ impl Hsv {
         let Color { r, g, b, .. } = Color::from(self);
         [to_u8(r), to_u8(g), to_u8(b)]
     }
+
+    /// Format the color as a hex string, such as `#ff8000`.
+    ///
+    /// The alpha is only included if the color is translucent, such as `#ff800080`.
+    pub fn to_hex(self) -> String {
+        let [r, g, b, a] = self.to_rgba8();
+
+        match a {
+            u8::MAX => format!("#{r:02x}{g:02x}{b:02x}"),
+            a => format!("#{r:02x}{g:02x}{b:02x}{a:02x}"),
+        }
+    }
+
+    /// Parse a hex string in the form of `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`.
+    ///
+    /// The leading `#` is optional.
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+
+        // `from_str_radix` would also accept a sign.
+        if !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        let channel = |i: usize, width: usize| {
+            let value = u8::from_str_radix(hex.get(i * width..(i + 1) * width)?, 16).ok()?;
+
+            // Expand shorthand digits, e.g. `f` -> `ff`.
+            Some(match width {
+                1 => value * 0x11,
+                _ => value,
+            })
+        };
+
+        let width = match hex.len() {
+            3 | 4 => 1,
+            6 | 8 => 2,
+            _ => return None,
+        };
+
+        let rgb = [channel(0, width)?, channel(1, width)?, channel(2, width)?];
+        let alpha = match hex.len() / width {
+            4 => channel(3, width)?,
+            _ => u8::MAX,
+        };
+
+        let [r, g, b] = rgb;
+
+        Some(Self::from_rgba8([r, g, b, alpha]))
+    }
+}
+
+/// (De)serialize an [Hsv] as a hex string, using [Hsv::to_hex] and [Hsv::from_hex].
+///
+/// Use with `#[serde(with = "iced_color_picker::hsv::hex")]`.
+/// Unlike the default representation, this loses precision and the hue of greys.
+#[cfg(feature = "serde")]
+pub mod hex {
+    use super::Hsv;
+
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(hsv: &Hsv, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hsv.to_hex())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Hsv, D::Error> {
+        let hex = String::deserialize(deserializer)?;
+
+        Hsv::from_hex(&hex).ok_or_else(|| D::Error::custom(format!("invalid hex color: {hex}")))
+    }
+}
+
+/// Conversions between [Hsv] and the types of the [palette](::palette) crate.
+///
+/// [Hsv] is interpreted as gamma-encoded sRGB, the same as [palette::Hsva](::palette::Hsva).
+#[cfg(feature = "palette")]
+mod palette {
+    use super::Hsv;
+    use crate::widget::oklab::Oklch;
+
+    use iced_core::Color;
+    use palette::{Hsva, Oklcha, Srgba};
+
+    impl From<Hsva> for Hsv {
+        fn from(hsva: Hsva) -> Self {
+            Self::new(
+                hsva.hue.into_positive_degrees(),
+                hsva.saturation,
+                hsva.value,
+                hsva.alpha,
+            )
+        }
+    }
+
+    impl From<Hsv> for Hsva {
+        fn from(hsv: Hsv) -> Self {
+            let Hsv { h, s, v, a } = hsv.normalized();
+
+            Hsva::new(h, s, v, a)
+        }
+    }
+
+    impl From<Srgba> for Hsv {
+        fn from(srgba: Srgba) -> Self {
+            Self::from(Color::from_rgba(
+                srgba.red,
+                srgba.green,
+                srgba.blue,
+                srgba.alpha,
+            ))
+        }
+    }
+
+    impl From<Hsv> for Srgba {
+        fn from(hsv: Hsv) -> Self {
+            let Color { r, g, b, a } = Color::from(hsv);
+
+            Srgba::new(r, g, b, a)
+        }
+    }
+
+    impl From<Srgba<u8>> for Hsv {
+        fn from(srgba: Srgba<u8>) -> Self {
+            Self::from_rgba8([srgba.red, srgba.green, srgba.blue, srgba.alpha])
+        }
+    }
+
+    impl From<Hsv> for Srgba<u8> {
+        fn from(hsv: Hsv) -> Self {
+            let [r, g, b, a] = hsv.to_rgba8();
+
+            Srgba::new(r, g, b, a)
+        }
+    }
+
+    impl From<Oklcha> for Hsv {
+        /// Colors outside of sRGB have their chroma reduced, the same as converting from [Oklch].
+        fn from(oklcha: Oklcha) -> Self {
+            Self::from(Oklch::new(
+                oklcha.l,
+                oklcha.chroma,
+                oklcha.hue.into_positive_degrees(),
+                oklcha.alpha,
+            ))
+        }
+    }
+
+    impl From<Hsv> for Oklcha {
+        fn from(hsv: Hsv) -> Self {
+            let Oklch { l, c, h, alpha } = Oklch::from(hsv);
+
+            Oklcha::new(l, c, h, alpha)
+        }
+    }
 }
 
 fn to_u8(v: f32) -> u8 {
     (v * u8::MAX as f32).round() as u8
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{EPSILON, Hsv, hsva, wrap_hue};
+
+    #[test]
+    fn hex_round_trip() {
+        for hex in [
+            "#000000",
+            "#ffffff",
+            "#ff8000",
+            "#336699",
+            "#ff800080",
+            "#12345678",
+        ] {
+            assert_eq!(Hsv::from_hex(hex).map(Hsv::to_hex).as_deref(), Some(hex));
+        }
+    }
+
+    #[test]
+    fn hex_shorthand() {
+        assert_eq!(Hsv::from_hex("#f80"), Hsv::from_hex("#ff8800"));
+        assert_eq!(Hsv::from_hex("f808"), Hsv::from_hex("#ff880088"));
+        assert_eq!(Hsv::from_hex("FF8000"), Hsv::from_hex("#ff8000"));
+    }
+
+    #[test]
+    fn hex_malformed() {
+        for hex in [
+            "",
+            "#",
+            "#ff",
+            "#ff800",
+            "#ff8000f",
+            "#ff8000ff0",
+            "#+f+f+f",
+            "#-f-f-f",
+            "#ff 800",
+            "#gg8000",
+            "##ff8000",
+            "#ff8000 ",
+            "#ffé000",
+        ] {
+            assert_eq!(Hsv::from_hex(hex), None, "{hex}");
+        }
+    }
+
+    #[test]
+    fn wrap_hue_range() {
+        assert_eq!(wrap_hue(-1e-8), 0.0);
+        assert_eq!(wrap_hue(360.0), 0.0);
+        assert_eq!(wrap_hue(-90.0), 270.0);
+        assert_eq!(wrap_hue(720.5), 0.5);
+
+        for hue in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+            assert_eq!(wrap_hue(hue), 0.0);
+        }
+    }
+
+    #[test]
+    fn normalized_nan() {
+        let hsv = hsva(f32::NAN, f32::NAN, f32::NAN, f32::NAN).normalized();
+
+        assert_eq!((hsv.h, hsv.s, hsv.v, hsv.a), (0.0, 0.0, 0.0, 1.0));
+
+        let hsv = hsva(400.0, -1.0, 2.0, 0.5).normalized();
+
+        assert_eq!((hsv.h, hsv.s, hsv.v, hsv.a), (40.0, 0.0, 1.0, 0.5));
+    }
+
+    #[test]
+    fn approx_eq_hue_seam() {
+        assert!(hsva(359.99, 1.0, 1.0, 1.0).approx_eq(hsva(0.01, 1.0, 1.0, 1.0), EPSILON));
+        assert!(hsva(-0.01, 1.0, 1.0, 1.0).approx_eq(hsva(360.01, 1.0, 1.0, 1.0), EPSILON));
+        assert!(!hsva(350.0, 1.0, 1.0, 1.0).approx_eq(hsva(10.0, 1.0, 1.0, 1.0), EPSILON));
+        assert!(hsva(350.0, 1.0, 1.0, 1.0).approx_eq(hsva(10.0, 1.0, 1.0, 1.0), 21.0 / 360.0));
+        assert_eq!(hsva(359.99, 0.5, 0.5, 1.0), hsva(0.01, 0.5, 0.5, 1.0));
+    }
+}
@@ -0,0 +1,120 @@
+//! The Hue, Whiteness, Blackness color model used by CSS.
+
+use super::hsv::Hsv;
+use super::oklab::{Oklab, Oklch};
+
+use iced_core::Color;
+
+/// Hue, Whiteness, Blackness
+///
+/// If the whiteness and blackness add up to more than `1.0`, they are scaled down
+/// so that their sum is `1.0`, producing a shade of grey.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hwb {
+    /// The Hue component.
+    pub h: f32,
+    /// The Whiteness component.
+    pub w: f32,
+    /// The Blackness component.
+    pub b: f32,
+    /// The alpha component.
+    pub a: f32,
+}
+
+impl Default for Hwb {
+    fn default() -> Self {
+        Self {
+            h: Default::default(),
+            w: Default::default(),
+            b: 1.0,
+            a: 1.0,
+        }
+    }
+}
+
+pub fn hwb(hue: f32, whiteness: f32, blackness: f32) -> Hwb {
+    Hwb {
+        h: hue,
+        w: whiteness,
+        b: blackness,
+        a: 1.0,
+    }
+}
+
+impl Hwb {
+    /// Scale the whiteness and blackness so that they don't add up to more than `1.0`.
+    pub fn normalized(self) -> Self {
+        let w = self.w.max(0.0);
+        let b = self.b.max(0.0);
+        let sum = w + b;
+
+        match sum > 1.0 {
+            true => Self {
+                w: w / sum,
+                b: b / sum,
+                ..self
+            },
+            false => Self { w, b, ..self },
+        }
+    }
+}
+
+impl From<Hsv> for Hwb {
+    // https://en.wikipedia.org/wiki/HWB_color_model#Converting_to_and_from_HSV
+    fn from(Hsv { h, s, v, a }: Hsv) -> Self {
+        Self {
+            h,
+            w: (1.0 - s) * v,
+            b: 1.0 - v,
+            a,
+        }
+    }
+}
+
+impl From<Hwb> for Hsv {
+    fn from(hwb: Hwb) -> Self {
+        let Hwb { h, w, b, a } = hwb.normalized();
+
+        let v = 1.0 - b;
+        let s = if v == 0.0 { 0.0 } else { 1.0 - w / v };
+
+        Self { h, s, v, a }
+    }
+}
+
+impl From<Color> for Hwb {
+    fn from(color: Color) -> Self {
+        Self::from(Hsv::from(color))
+    }
+}
+
+impl From<Hwb> for Color {
+    fn from(hwb: Hwb) -> Self {
+        Self::from(Hsv::from(hwb))
+    }
+}
+
+impl From<Hwb> for Oklab {
+    fn from(hwb: Hwb) -> Self {
+        Self::from(Color::from(hwb))
+    }
+}
+
+impl From<Oklab> for Hwb {
+    fn from(oklab: Oklab) -> Self {
+        Self::from(Color::from(oklab))
+    }
+}
+
+impl From<Hwb> for Oklch {
+    fn from(hwb: Hwb) -> Self {
+        Self::from(Color::from(hwb))
+    }
+}
+
+impl From<Oklch> for Hwb {
+    fn from(oklch: Oklch) -> Self {
+        Self::from(Color::from(oklch))
+    }
+}
@@ -0,0 +1,361 @@
+//! The CIE XYZ, CIELAB and CIELCh color spaces.
+//!
+//! All of them use the D65 white point, the same as sRGB, so no chromatic adaptation is needed.
+//! Conversions to and from [Color] go through linear sRGB.
+
+use super::cmyk::Cmyk;
+use super::gamut::{self, Gamut, GamutColor};
+use super::hsv::Hsv;
+use super::hwb::Hwb;
+use super::oklab::{Oklab, Oklch};
+
+use iced_core::Color;
+
+/// The D65 white point in CIE XYZ, normalized so that `y` is `1.0`.
+pub const D65: [f32; 3] = [0.950_455_9, 1.0, 1.089_057_8];
+
+/// The CIE 1931 XYZ color space, relative to the [D65] white point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Xyz {
+    /// The x component.
+    pub x: f32,
+    /// The y component, also known as the relative luminance.
+    pub y: f32,
+    /// The z component.
+    pub z: f32,
+    /// The alpha component.
+    pub a: f32,
+}
+
+/// The CIELAB color space, relative to the [D65] white point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lab {
+    /// The lightness component, from `0.0` to `100.0`.
+    pub l: f32,
+    /// The green/red axis.
+    pub a: f32,
+    /// The blue/yellow axis.
+    pub b: f32,
+    /// The alpha component.
+    pub alpha: f32,
+}
+
+/// Lightness, Chroma, Hue. The polar form of [Lab].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lch {
+    /// The lightness component, from `0.0` to `100.0`.
+    pub l: f32,
+    /// The chroma component.
+    pub c: f32,
+    /// The hue component in degrees.
+    pub h: f32,
+    /// The alpha component.
+    pub alpha: f32,
+}
+
+impl Xyz {
+    pub const fn new(x: f32, y: f32, z: f32, a: f32) -> Self {
+        Self { x, y, z, a }
+    }
+
+    /// Convert to a [GamutColor] in the given [Gamut]. The result is not clipped.
+    pub fn to_gamut(self, gamut: Gamut) -> GamutColor {
+        let linear = gamut::mul(gamut.xyz_to_rgb(), [self.x, self.y, self.z]);
+
+        GamutColor::from_linear(gamut, linear, self.a)
+    }
+}
+
+impl From<GamutColor> for Xyz {
+    fn from(color: GamutColor) -> Self {
+        let [x, y, z] = gamut::mul(color.gamut.rgb_to_xyz(), color.to_linear());
+
+        Self {
+            x,
+            y,
+            z,
+            a: color.a,
+        }
+    }
+}
+
+impl From<Color> for Xyz {
+    fn from(color: Color) -> Self {
+        Self::from(GamutColor::from(color))
+    }
+}
+
+impl From<Xyz> for Color {
+    /// Colors outside of sRGB are clipped.
+    fn from(xyz: Xyz) -> Self {
+        xyz.to_gamut(Gamut::Srgb).to_srgb()
+    }
+}
+
+impl From<Xyz> for Oklab {
+    fn from(xyz: Xyz) -> Self {
+        Oklab::from_xyz([xyz.x, xyz.y, xyz.z], xyz.a)
+    }
+}
+
+impl From<Oklab> for Xyz {
+    fn from(oklab: Oklab) -> Self {
+        let [x, y, z] = oklab.to_xyz();
+
+        Self {
+            x,
+            y,
+            z,
+            a: oklab.alpha,
+        }
+    }
+}
+
+impl Lab {
+    pub const fn new(l: f32, a: f32, b: f32, alpha: f32) -> Self {
+        Self { l, a, b, alpha }
+    }
+}
+
+impl From<Xyz> for Lab {
+    // http://www.brucelindbloom.com/index.html?Eqn_XYZ_to_Lab.html
+    fn from(Xyz { x, y, z, a }: Xyz) -> Self {
+        let f = |t: f32| match t > EPSILON {
+            true => t.cbrt(),
+            false => (KAPPA * t + 16.0) / 116.0,
+        };
+
+        let [fx, fy, fz] = [f(x / D65[0]), f(y / D65[1]), f(z / D65[2])];
+
+        Self {
+            l: 116.0 * fy - 16.0,
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+            alpha: a,
+        }
+    }
+}
+
+impl From<Lab> for Xyz {
+    // http://www.brucelindbloom.com/index.html?Eqn_Lab_to_XYZ.html
+    fn from(Lab { l, a, b, alpha }: Lab) -> Self {
+        let fy = (l + 16.0) / 116.0;
+        let fx = fy + a / 500.0;
+        let fz = fy - b / 200.0;
+
+        let f = |t: f32| match t * t * t > EPSILON {
+            true => t * t * t,
+            false => (116.0 * t - 16.0) / KAPPA,
+        };
+
+        let y = match l > KAPPA * EPSILON {
+            true => fy * fy * fy,
+            false => l / KAPPA,
+        };
+
+        Self {
+            x: f(fx) * D65[0],
+            y: y * D65[1],
+            z: f(fz) * D65[2],
+            a: alpha,
+        }
+    }
+}
+
+impl Lch {
+    pub const fn new(l: f32, c: f32, h: f32, alpha: f32) -> Self {
+        Self { l, c, h, alpha }
+    }
+}
+
+impl From<Lab> for Lch {
+    fn from(Lab { l, a, b, alpha }: Lab) -> Self {
+        let c = (a * a + b * b).sqrt();
+        let h = b.atan2(a).to_degrees().rem_euclid(360.0);
+
+        Self { l, c, h, alpha }
+    }
+}
+
+impl From<Lch> for Lab {
+    fn from(Lch { l, c, h, alpha }: Lch) -> Self {
+        let (sin, cos) = h.to_radians().sin_cos();
+
+        Self {
+            l,
+            a: c * cos,
+            b: c * sin,
+            alpha,
+        }
+    }
+}
+
+impl From<GamutColor> for Lab {
+    fn from(color: GamutColor) -> Self {
+        Self::from(Xyz::from(color))
+    }
+}
+
+impl From<Oklab> for Lab {
+    fn from(oklab: Oklab) -> Self {
+        Self::from(Xyz::from(oklab))
+    }
+}
+
+impl From<Lab> for Oklab {
+    fn from(lab: Lab) -> Self {
+        Self::from(Xyz::from(lab))
+    }
+}
+
+impl From<Color> for Lab {
+    fn from(color: Color) -> Self {
+        Self::from(Xyz::from(color))
+    }
+}
+
+impl From<Lab> for Color {
+    fn from(lab: Lab) -> Self {
+        Self::from(Xyz::from(lab))
+    }
+}
+
+impl From<Color> for Lch {
+    fn from(color: Color) -> Self {
+        Self::from(Lab::from(color))
+    }
+}
+
+impl From<Lch> for Color {
+    fn from(lch: Lch) -> Self {
+        Self::from(Lab::from(lch))
+    }
+}
+
+impl From<Hsv> for Xyz {
+    fn from(hsv: Hsv) -> Self {
+        Self::from(Color::from(hsv))
+    }
+}
+
+impl From<Xyz> for Hsv {
+    fn from(xyz: Xyz) -> Self {
+        Self::from(Color::from(xyz))
+    }
+}
+
+impl From<Hsv> for Lab {
+    fn from(hsv: Hsv) -> Self {
+        Self::from(Color::from(hsv))
+    }
+}
+
+impl From<Lab> for Hsv {
+    fn from(lab: Lab) -> Self {
+        Self::from(Color::from(lab))
+    }
+}
+
+impl From<Hsv> for Lch {
+    fn from(hsv: Hsv) -> Self {
+        Self::from(Color::from(hsv))
+    }
+}
+
+impl From<Lch> for Hsv {
+    fn from(lch: Lch) -> Self {
+        Self::from(Color::from(lch))
+    }
+}
+
+impl From<Lch> for Oklab {
+    fn from(lch: Lch) -> Self {
+        Self::from(Lab::from(lch))
+    }
+}
+
+impl From<Oklab> for Lch {
+    fn from(oklab: Oklab) -> Self {
+        Self::from(Lab::from(oklab))
+    }
+}
+
+impl From<Lab> for Oklch {
+    fn from(lab: Lab) -> Self {
+        Self::from(Oklab::from(lab))
+    }
+}
+
+impl From<Oklch> for Lab {
+    fn from(oklch: Oklch) -> Self {
+        Self::from(Oklab::from(oklch))
+    }
+}
+
+impl From<Lch> for Oklch {
+    fn from(lch: Lch) -> Self {
+        Self::from(Lab::from(lch))
+    }
+}
+
+impl From<Oklch> for Lch {
+    fn from(oklch: Oklch) -> Self {
+        Self::from(Lab::from(oklch))
+    }
+}
+
+impl From<Cmyk> for Lab {
+    fn from(cmyk: Cmyk) -> Self {
+        Self::from(Color::from(cmyk))
+    }
+}
+
+impl From<Lab> for Cmyk {
+    fn from(lab: Lab) -> Self {
+        Self::from(Color::from(lab))
+    }
+}
+
+impl From<Cmyk> for Lch {
+    fn from(cmyk: Cmyk) -> Self {
+        Self::from(Color::from(cmyk))
+    }
+}
+
+impl From<Lch> for Cmyk {
+    fn from(lch: Lch) -> Self {
+        Self::from(Color::from(lch))
+    }
+}
+
+impl From<Hwb> for Lab {
+    fn from(hwb: Hwb) -> Self {
+        Self::from(Color::from(hwb))
+    }
+}
+
+impl From<Lab> for Hwb {
+    fn from(lab: Lab) -> Self {
+        Self::from(Color::from(lab))
+    }
+}
+
+impl From<Hwb> for Lch {
+    fn from(hwb: Hwb) -> Self {
+        Self::from(Color::from(hwb))
+    }
+}
+
+impl From<Lch> for Hwb {
+    fn from(lch: Lch) -> Self {
+        Self::from(Color::from(lch))
+    }
+}
+
+// CIE standard: 216 / 24389
+const EPSILON: f32 = 0.008_856_452;
+// CIE standard: 24389 / 27
+const KAPPA: f32 = 903.296_3;
@@ -0,0 +1,185 @@
+//! The OKLab and OKLCH perceptual color spaces.
+//!
+//! Matrices are taken from: <https://www.w3.org/TR/css-color-4/#color-conversion-code>
+
+use super::gamut::{self, Gamut, GamutColor, GamutMapping, Matrix};
+use super::hsv::Hsv;
+
+use iced_core::Color;
+
+/// Lightness, and the a (green/red) and b (blue/yellow) axes of the OKLab color space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Oklab {
+    /// The lightness component, from `0.0` to `1.0`.
+    pub l: f32,
+    /// The green/red axis.
+    pub a: f32,
+    /// The blue/yellow axis.
+    pub b: f32,
+    /// The alpha component.
+    pub alpha: f32,
+}
+
+/// Lightness, Chroma, Hue. The polar form of [Oklab].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Oklch {
+    /// The lightness component, from `0.0` to `1.0`.
+    pub l: f32,
+    /// The chroma component.
+    pub c: f32,
+    /// The hue component in degrees.
+    pub h: f32,
+    /// The alpha component.
+    pub alpha: f32,
+}
+
+impl Oklab {
+    pub const fn new(l: f32, a: f32, b: f32, alpha: f32) -> Self {
+        Self { l, a, b, alpha }
+    }
+
+    /// Create an [Oklab] color from CIE XYZ (D65).
+    pub fn from_xyz(xyz: [f32; 3], alpha: f32) -> Self {
+        let [l, m, s] = gamut::mul(&XYZ_TO_LMS, xyz).map(f32::cbrt);
+        let [l, a, b] = gamut::mul(&LMS_TO_OKLAB, [l, m, s]);
+
+        Self { l, a, b, alpha }
+    }
+
+    /// Convert an [Oklab] color to CIE XYZ (D65).
+    pub fn to_xyz(self) -> [f32; 3] {
+        let lms = gamut::mul(&OKLAB_TO_LMS, [self.l, self.a, self.b]).map(|v| v * v * v);
+
+        gamut::mul(&LMS_TO_XYZ, lms)
+    }
+
+    /// Convert to a [GamutColor] in the given [Gamut]. The result is not clipped.
+    pub fn to_gamut(self, gamut: Gamut) -> GamutColor {
+        GamutColor::from_linear(
+            gamut,
+            gamut::mul(gamut.xyz_to_rgb(), self.to_xyz()),
+            self.alpha,
+        )
+    }
+
+    /// The euclidean distance between two [Oklab] colors, also known as deltaEOK.
+    pub fn distance(self, other: Self) -> f32 {
+        let dl = self.l - other.l;
+        let da = self.a - other.a;
+        let db = self.b - other.b;
+
+        (dl * dl + da * da + db * db).sqrt()
+    }
+}
+
+impl From<GamutColor> for Oklab {
+    fn from(color: GamutColor) -> Self {
+        let xyz = gamut::mul(color.gamut.rgb_to_xyz(), color.to_linear());
+
+        Self::from_xyz(xyz, color.a)
+    }
+}
+
+impl From<Color> for Oklab {
+    fn from(color: Color) -> Self {
+        Self::from(GamutColor::from(color))
+    }
+}
+
+impl From<Oklab> for Color {
+    fn from(color: Oklab) -> Self {
+        let color = GamutMapping::ReduceChroma.map(color.to_gamut(Gamut::Srgb), Gamut::Srgb);
+
+        Color::from_rgba(color.r, color.g, color.b, color.a)
+    }
+}
+
+impl From<Hsv> for Oklab {
+    fn from(hsv: Hsv) -> Self {
+        Self::from(Color::from(hsv))
+    }
+}
+
+impl From<Oklab> for Hsv {
+    fn from(color: Oklab) -> Self {
+        Self::from(Color::from(color))
+    }
+}
+
+impl Oklch {
+    pub const fn new(l: f32, c: f32, h: f32, alpha: f32) -> Self {
+        Self { l, c, h, alpha }
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(Oklab { l, a, b, alpha }: Oklab) -> Self {
+        let c = (a * a + b * b).sqrt();
+        let h = b.atan2(a).to_degrees().rem_euclid(360.0);
+
+        Self { l, c, h, alpha }
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(Oklch { l, c, h, alpha }: Oklch) -> Self {
+        let (sin, cos) = h.to_radians().sin_cos();
+
+        Self {
+            l,
+            a: c * cos,
+            b: c * sin,
+            alpha,
+        }
+    }
+}
+
+impl From<Color> for Oklch {
+    fn from(color: Color) -> Self {
+        Self::from(Oklab::from(color))
+    }
+}
+
+impl From<Oklch> for Color {
+    fn from(color: Oklch) -> Self {
+        Self::from(Oklab::from(color))
+    }
+}
+
+impl From<Hsv> for Oklch {
+    fn from(hsv: Hsv) -> Self {
+        Self::from(Oklab::from(hsv))
+    }
+}
+
+impl From<Oklch> for Hsv {
+    fn from(color: Oklch) -> Self {
+        Self::from(Oklab::from(color))
+    }
+}
+
+const XYZ_TO_LMS: Matrix = [
+    [0.819_022_4, 0.361_906_26, -0.128_873_78],
+    [0.032_983_655, 0.929_286_86, 0.036_144_666],
+    [0.048_177_19, 0.264_239_53, 0.633_547_8],
+];
+
+const LMS_TO_XYZ: Matrix = [
+    [1.226_88, -0.557_815, 0.281_391_05],
+    [-0.040_575_745, 1.112_286_8, -0.071_711_06],
+    [-0.076_372_94, -0.421_493_33, 1.586_924],
+];
+
+const LMS_TO_OKLAB: Matrix = [
+    [0.210_454_27, 0.793_617_8, -0.004_072_043],
+    [1.977_998_5, -2.428_592_2, 0.450_593_7],
+    [0.025_904_042, 0.782_771_7, -0.808_675_77],
+];
+
+const OKLAB_TO_LMS: Matrix = [
+    [1.0, 0.396_337_78, 0.215_803_76],
+    [1.0, -0.105_561_346, -0.063_854_17],
+    [1.0, -0.089_484_18, -1.291_485_5],
+];
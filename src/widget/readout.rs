@@ -0,0 +1,42 @@
+//! Formats for the value shown next to the marker while dragging.
+
+use super::hsv::Hsv;
+
+/// The format of the value shown by [ColorPicker::readout](super::ColorPicker::readout).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Readout {
+    /// A hex string, such as `#ff8000`.
+    #[default]
+    Hex,
+    /// The [Hsv] components in degrees and percent, such as `hsv(30° 100% 100%)`.
+    Hsv,
+    /// The RGB components from `0` to `255`, such as `rgb(255 128 0)`.
+    Rgb,
+}
+
+impl Readout {
+    /// Format a color. The alpha is only included if the color is translucent.
+    pub fn format(self, color: Hsv) -> String {
+        let color = color.normalized();
+
+        let alpha = match color.a < 1.0 {
+            true => format!(" / {:.0}%", color.a * 100.0),
+            false => String::new(),
+        };
+
+        match self {
+            Readout::Hex => color.to_hex(),
+            Readout::Hsv => format!(
+                "hsv({:.0}° {:.0}% {:.0}%{alpha})",
+                color.h,
+                color.s * 100.0,
+                color.v * 100.0
+            ),
+            Readout::Rgb => {
+                let [r, g, b] = color.to_rgb8();
+                format!("rgb({r} {g} {b}{alpha})")
+            }
+        }
+    }
+}
@@ -0,0 +1,51 @@
+//! Rounding picked colors to steps, or to the nearest color of a palette.
+
+use super::delta_e;
+use super::hsv::{Hsv, wrap_hue};
+
+/// How the colors picked by a [ColorPicker](super::ColorPicker) are snapped.
+///
+/// Steps that are [None], or not positive, are ignored.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Snap {
+    /// Round the hue to multiples of this many degrees.
+    pub hue: Option<f32>,
+    /// Round the saturation to multiples of this fraction, such as `0.1` for 10% steps.
+    pub saturation: Option<f32>,
+    /// Round the value to multiples of this fraction, such as `0.1` for 10% steps.
+    pub value: Option<f32>,
+    /// Replace the color with the closest color of the palette, ignoring the steps.
+    ///
+    /// Colors are compared with [delta_e::oklab], and the alpha of the picked color is kept.
+    pub palette: Vec<Hsv>,
+}
+
+impl Snap {
+    /// Snap a color to the steps, or to the palette if it isn't empty.
+    pub fn apply(&self, color: Hsv) -> Hsv {
+        let nearest = self
+            .palette
+            .iter()
+            .min_by(|a, b| delta_e::oklab(color, **a).total_cmp(&delta_e::oklab(color, **b)));
+
+        if let Some(nearest) = nearest {
+            return Hsv {
+                a: color.a,
+                ..*nearest
+            };
+        }
+
+        let round = |component: f32, step: Option<f32>| match step {
+            Some(step) if step > 0.0 => (component / step).round() * step,
+            _ => component,
+        };
+
+        Hsv {
+            h: wrap_hue(round(color.h, self.hue)),
+            s: round(color.s, self.saturation).clamp(0.0, 1.0),
+            v: round(color.v, self.value).clamp(0.0, 1.0),
+            a: color.a,
+        }
+    }
+}
@@ -1,11 +1,22 @@
 //! helper functions to draw different spectrums
 
-use super::hsv;
+use super::Orientation;
+use super::gamut::{Gamut, GamutMapping};
+use super::hsv::{Hsv, WorkingSpace, hsv};
+use super::hwb::hwb;
 
-use iced_core::{Color, Point, Size};
-use iced_graphics::geometry::{self, Frame};
+use iced_core::alignment;
+use iced_core::text::Alignment;
+use iced_core::{Color, Point, Rectangle, Size, Vector};
+use iced_graphics::geometry::{self, Frame, Path, Stroke, Text};
 
-pub fn saturation_value<Renderer: geometry::Renderer>(frame: &mut Frame<Renderer>, hue: f32) {
+pub fn saturation_value<Renderer: geometry::Renderer>(
+    frame: &mut Frame<Renderer>,
+    hue: f32,
+    space: WorkingSpace,
+    gamut: Gamut,
+    mapping: GamutMapping,
+) {
     use std::num::NonZeroUsize;
 
     // Done for performance. Lower quantum = higher resolution. Hard coded for now.
@@ -27,7 +38,39 @@ pub fn saturation_value<Renderer: geometry::Renderer>(frame: &mut Frame<Renderer
             frame.fill_rectangle(
                 Point::new(col, row),
                 Size::new(quantization, quantization),
-                Color::from(hsv(hue, sat, value)),
+                gamut.display(hsv(hue, sat, value), space, mapping),
+            );
+        }
+    }
+}
+
+pub fn whiteness_blackness<Renderer: geometry::Renderer>(
+    frame: &mut Frame<Renderer>,
+    hue: f32,
+    space: WorkingSpace,
+    gamut: Gamut,
+    mapping: GamutMapping,
+) {
+    // Done for performance. Lower quantum = higher resolution. Hard coded for now.
+    const QUANTIZATION: usize = 2;
+
+    let cols = frame.width() as usize / QUANTIZATION;
+    let rows = frame.height() as usize / QUANTIZATION;
+
+    let quantization = QUANTIZATION as f32;
+
+    for col in 0..cols {
+        for row in 0..rows {
+            let col = col as f32 * quantization;
+            let row = row as f32 * quantization;
+
+            let whiteness = col / frame.width();
+            let blackness = row / frame.height();
+
+            frame.fill_rectangle(
+                Point::new(col, row),
+                Size::new(quantization, quantization),
+                gamut.display(Hsv::from(hwb(hue, whiteness, blackness)), space, mapping),
             );
         }
     }
@@ -37,36 +80,437 @@ pub fn hue_vertical<Renderer: geometry::Renderer>(
     frame: &mut Frame<Renderer>,
     saturation: f32,
     value: f32,
+    space: WorkingSpace,
+    gamut: Gamut,
+    mapping: GamutMapping,
 ) {
-    let width = frame.width();
-    let rows = frame.height() as usize;
+    // The hue increases down the y-axis.
+    strip(frame, Orientation::Vertical, |t| {
+        gamut.display(hsv((1.0 - t) * 360., saturation, value), space, mapping)
+    });
+}
+
+pub fn hue_horizontal<Renderer: geometry::Renderer>(
+    frame: &mut Frame<Renderer>,
+    saturation: f32,
+    value: f32,
+    space: WorkingSpace,
+    gamut: Gamut,
+    mapping: GamutMapping,
+) {
+    strip(frame, Orientation::Horizontal, |t| {
+        gamut.display(hsv(t * 360., saturation, value), space, mapping)
+    });
+}
+
+/// Draw a ring where the hue increases counter-clockwise, starting from the right.
+pub fn hue_ring<Renderer: geometry::Renderer>(
+    frame: &mut Frame<Renderer>,
+    saturation: f32,
+    value: f32,
+    space: WorkingSpace,
+    gamut: Gamut,
+    mapping: GamutMapping,
+) {
+    const SEGMENTS: usize = 360;
+
+    let (center, inner, outer) = ring(frame.size());
+
+    let point = |degrees: f32, radius: f32| {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        Point::new(center.x + radius * cos, center.y - radius * sin)
+    };
+
+    let step = 360.0 / SEGMENTS as f32;
+
+    for segment in 0..SEGMENTS {
+        let start = segment as f32 * step;
+        // Overlap the next segment slightly to hide seams.
+        let end = start + step * 1.5;
+
+        let path = Path::new(|builder| {
+            builder.move_to(point(start, inner));
+            builder.line_to(point(start, outer));
+            builder.line_to(point(end, outer));
+            builder.line_to(point(end, inner));
+            builder.close();
+        });
+
+        frame.fill(
+            &path,
+            gamut.display(hsv(start + step / 2.0, saturation, value), space, mapping),
+        );
+    }
+}
+
+/// The center, inner radius and outer radius of the ring drawn by [hue_ring].
+pub(crate) fn ring(size: Size) -> (Point, f32, f32) {
+    // The thickness of the ring, relative to its outer radius.
+    const THICKNESS: f32 = 0.25;
+
+    let outer = size.width.min(size.height) / 2.0;
+
+    (
+        Point::new(size.width / 2.0, size.height / 2.0),
+        outer * (1.0 - THICKNESS),
+        outer,
+    )
+}
+
+/// Draw ticks along the edges of a 1-Dimensional spectrum, for values from `0.0` to `1.0`.
+///
+/// The value increases from left to right, or from bottom to top.
+pub fn ticks<Renderer: geometry::Renderer>(
+    frame: &mut Frame<Renderer>,
+    orientation: Orientation,
+    values: impl IntoIterator<Item = f32>,
+    color: Color,
+) {
+    let (width, height) = (frame.width(), frame.height());
+
+    for value in values {
+        let (start, end) = match orientation {
+            Orientation::Horizontal => (
+                Point::new(value * width, 0.0),
+                Point::new(value * width, height),
+            ),
+            Orientation::Vertical => (
+                Point::new(0.0, (1.0 - value) * height),
+                Point::new(width, (1.0 - value) * height),
+            ),
+        };
+
+        tick(frame, start, end, color);
+    }
+}
+
+/// Draw ticks along the edges of the ring drawn by [hue_ring], for hues in degrees.
+pub fn ring_ticks<Renderer: geometry::Renderer>(
+    frame: &mut Frame<Renderer>,
+    hues: impl IntoIterator<Item = f32>,
+    color: Color,
+) {
+    let (center, inner, outer) = ring(frame.size());
+
+    for hue in hues {
+        let (sin, cos) = hue.to_radians().sin_cos();
+        let point = |radius: f32| Point::new(center.x + radius * cos, center.y - radius * sin);
+
+        tick(frame, point(inner), point(outer), color);
+    }
+}
+
+/// Draw labels next to the ticks of a 1-Dimensional spectrum, for values from `0.0` to `1.0`.
+///
+/// The value increases from left to right, or from bottom to top.
+pub fn tick_labels<Renderer: geometry::Renderer>(
+    frame: &mut Frame<Renderer>,
+    orientation: Orientation,
+    labels: impl IntoIterator<Item = (f32, String)>,
+    color: Color,
+    size: f32,
+) {
+    // The gap between a tick and its label.
+    const PADDING: f32 = 2.0;
+
+    let (width, height) = (frame.width(), frame.height());
+
+    for (value, content) in labels {
+        let (position, align_x, align_y) = match orientation {
+            Orientation::Horizontal => (
+                Point::new(value * width + PADDING, height / 2.0),
+                Alignment::Left,
+                alignment::Vertical::Center,
+            ),
+            Orientation::Vertical => (
+                Point::new(width / 2.0, (1.0 - value) * height - PADDING),
+                Alignment::Center,
+                alignment::Vertical::Bottom,
+            ),
+        };
+
+        frame.fill_text(Text {
+            content,
+            position,
+            color,
+            size: size.into(),
+            align_x,
+            align_y,
+            ..Text::default()
+        });
+    }
+}
+
+/// Draw labels inside the ring drawn by [hue_ring], for hues in degrees.
+pub fn ring_tick_labels<Renderer: geometry::Renderer>(
+    frame: &mut Frame<Renderer>,
+    labels: impl IntoIterator<Item = (f32, String)>,
+    color: Color,
+    size: f32,
+) {
+    let (center, inner, _) = ring(frame.size());
+    let radius = inner - size;
+
+    for (hue, content) in labels {
+        let (sin, cos) = hue.to_radians().sin_cos();
+
+        frame.fill_text(Text {
+            content,
+            position: Point::new(center.x + radius * cos, center.y - radius * sin),
+            color,
+            size: size.into(),
+            align_x: Alignment::Center,
+            align_y: alignment::Vertical::Center,
+            ..Text::default()
+        });
+    }
+}
+
+/// Draw a tick at both ends of a line across a spectrum.
+fn tick<Renderer: geometry::Renderer>(
+    frame: &mut Frame<Renderer>,
+    start: Point,
+    end: Point,
+    color: Color,
+) {
+    // The length of each tick, relative to the line.
+    const LENGTH: f32 = 0.25;
+
+    let stroke = Stroke::default().with_color(color).with_width(1.0);
+    let length = (end - start) * LENGTH;
+
+    frame.stroke(&Path::line(start, start + length), stroke);
+    frame.stroke(&Path::line(end - length, end), stroke);
+}
+
+/// Outline the region of a saturation-value spectrum in a wide [Gamut] that can be represented in sRGB.
+pub fn srgb_boundary<Renderer: geometry::Renderer>(
+    frame: &mut Frame<Renderer>,
+    hue: f32,
+    space: WorkingSpace,
+    gamut: Gamut,
+    color: Color,
+) {
+    saturation_value_boundary(frame, hue, color, |hsv| gamut.contains_in_srgb(hsv, space));
+}
+
+/// Outline the region of a saturation-value spectrum where `inside` is true.
+pub fn saturation_value_boundary<Renderer: geometry::Renderer>(
+    frame: &mut Frame<Renderer>,
+    hue: f32,
+    color: Color,
+    inside: impl Fn(Hsv) -> bool,
+) {
+    const QUANTIZATION: usize = 2;
+
+    let cols = frame.width() as usize / QUANTIZATION;
+    let rows = frame.height() as usize / QUANTIZATION;
+
+    let quantization = QUANTIZATION as f32;
+    let (width, height) = (frame.width(), frame.height());
+
+    let inside = |col: usize, row: usize| {
+        let sat = (col as f32 * quantization) / width;
+        let value = 1.0 - (row as f32 * quantization) / height;
+
+        inside(hsv(hue, sat, value))
+    };
+
+    let mut previous_row: Vec<bool> = (0..cols).map(|col| inside(col, 0)).collect();
 
     for row in 0..rows {
-        let hue = (row as f32 / rows as f32) * 360.;
+        let current_row: Vec<bool> = (0..cols).map(|col| inside(col, row)).collect();
+
+        for col in 0..cols {
+            let left = col.checked_sub(1).map(|col| current_row[col]);
+            let edge = left.is_some_and(|left| left != current_row[col])
+                || previous_row[col] != current_row[col];
+
+            if edge {
+                frame.fill_rectangle(
+                    Point::new(col as f32 * quantization, row as f32 * quantization),
+                    Size::new(quantization, quantization),
+                    color,
+                );
+            }
+        }
+
+        previous_row = current_row;
+    }
+}
+
+/// Draw a 1-Dimensional spectrum, where `color` produces the color for a value from `0.0` to `1.0`.
+///
+/// The value increases from left to right, or from bottom to top.
+pub fn strip<Renderer: geometry::Renderer>(
+    frame: &mut Frame<Renderer>,
+    orientation: Orientation,
+    color: impl Fn(f32) -> Color,
+) {
+    match orientation {
+        Orientation::Horizontal => {
+            let height = frame.height();
+            let cols = frame.width() as usize;
+
+            for col in 0..cols {
+                frame.fill_rectangle(
+                    Point::new(col as f32, 0.),
+                    Size::new(1.0, height),
+                    color(col as f32 / cols as f32),
+                );
+            }
+        }
+        Orientation::Vertical => {
+            let width = frame.width();
+            let rows = frame.height() as usize;
+
+            for row in 0..rows {
+                frame.fill_rectangle(
+                    Point::new(0., row as f32),
+                    Size::new(width, 1.0),
+                    color(1.0 - row as f32 / rows as f32),
+                );
+            }
+        }
+    }
+}
+
+/// Draw a gradient from transparent to opaque, from left to right or from bottom to top.
+pub fn alpha<Renderer: geometry::Renderer>(
+    frame: &mut Frame<Renderer>,
+    orientation: Orientation,
+    color: Color,
+) {
+    strip(frame, orientation, |alpha| Color { a: alpha, ..color });
+}
+
+/// Fill a rectangle with a checkerboard, used to show translucent colors.
+pub fn checkerboard<Renderer: geometry::Renderer>(
+    frame: &mut Frame<Renderer>,
+    bounds: Rectangle,
+    tile_size: f32,
+    [light, dark]: [Color; 2],
+) {
+    let tile_size = tile_size.max(1.0);
 
-        frame.fill_rectangle(
-            Point::new(0., row as f32),
-            Size::new(width, 1.0),
-            Color::from(hsv(hue, saturation, value)),
+    frame.fill_rectangle(bounds.position(), bounds.size(), light);
+
+    for (col, row) in tiles(bounds, tile_size) {
+        let position = bounds.position() + Vector::new(col as f32, row as f32) * tile_size;
+
+        let size = Size::new(
+            tile_size.min(bounds.x + bounds.width - position.x),
+            tile_size.min(bounds.y + bounds.height - position.y),
         );
+
+        frame.fill_rectangle(position, size, dark);
     }
 }
 
-pub fn hue_horizontal<Renderer: geometry::Renderer>(
+/// Fill a circle with a checkerboard, used to show translucent colors.
+pub fn checkerboard_circle<Renderer: geometry::Renderer>(
     frame: &mut Frame<Renderer>,
-    saturation: f32,
-    value: f32,
+    center: Point,
+    radius: f32,
+    tile_size: f32,
+    [light, dark]: [Color; 2],
 ) {
-    let height = frame.height();
-    let cols = frame.width() as usize;
+    // Number of sides of the polygon approximating the circle.
+    const SIDES: usize = 32;
 
-    for col in 0..cols {
-        let hue = (col as f32 / cols as f32) * 360.;
+    let tile_size = tile_size.max(1.0);
+
+    frame.fill(&Path::circle(center, radius), light);
 
-        frame.fill_rectangle(
-            Point::new(col as f32, 0.),
-            Size::new(1.0, height),
-            Color::from(hsv(hue, saturation, value)),
+    let circle: Vec<Point> = (0..SIDES)
+        .map(|side| {
+            let (sin, cos) = (side as f32 / SIDES as f32 * std::f32::consts::TAU).sin_cos();
+            center + Vector::new(cos, sin) * radius
+        })
+        .collect();
+
+    let bounds = Rectangle::new(
+        center - Vector::new(radius, radius),
+        Size::new(radius * 2.0, radius * 2.0),
+    );
+
+    for (col, row) in tiles(bounds, tile_size) {
+        let tile = Rectangle::new(
+            bounds.position() + Vector::new(col as f32, row as f32) * tile_size,
+            Size::new(tile_size, tile_size),
         );
+
+        let polygon = clip_polygon(&circle, tile);
+
+        if polygon.len() >= 3 {
+            frame.fill(
+                &Path::new(|builder| {
+                    builder.move_to(polygon[0]);
+                    polygon[1..]
+                        .iter()
+                        .for_each(|point| builder.line_to(*point));
+                    builder.close();
+                }),
+                dark,
+            );
+        }
     }
 }
+
+/// The (column, row) of every dark tile of a checkerboard covering the bounds.
+fn tiles(bounds: Rectangle, tile_size: f32) -> impl Iterator<Item = (usize, usize)> {
+    let cols = (bounds.width / tile_size).ceil() as usize;
+    let rows = (bounds.height / tile_size).ceil() as usize;
+
+    (0..rows)
+        .flat_map(move |row| (0..cols).map(move |col| (col, row)))
+        .filter(|(col, row)| (col + row) % 2 == 1)
+}
+
+/// Clip a convex polygon to a rectangle (Sutherland-Hodgman).
+fn clip_polygon(polygon: &[Point], rectangle: Rectangle) -> Vec<Point> {
+    // (is the edge vertical, position of the edge, is the edge a lower bound)
+    let edges = [
+        (true, rectangle.x, true),
+        (true, rectangle.x + rectangle.width, false),
+        (false, rectangle.y, true),
+        (false, rectangle.y + rectangle.height, false),
+    ];
+
+    edges
+        .iter()
+        .fold(polygon.to_vec(), |polygon, &(vertical, limit, lower)| {
+            let axis = |p: Point| match vertical {
+                true => p.x,
+                false => p.y,
+            };
+
+            let inside = |p: Point| match lower {
+                true => axis(p) >= limit,
+                false => axis(p) <= limit,
+            };
+
+            let intersect = |a: Point, b: Point| {
+                let t = (limit - axis(a)) / (axis(b) - axis(a));
+                a + (b - a) * t
+            };
+
+            let mut output = Vec::with_capacity(polygon.len() + 1);
+
+            for (i, &current) in polygon.iter().enumerate() {
+                let previous = polygon[(i + polygon.len() - 1) % polygon.len()];
+
+                match (inside(previous), inside(current)) {
+                    (true, true) => output.push(current),
+                    (true, false) => output.push(intersect(previous, current)),
+                    (false, true) => {
+                        output.push(intersect(previous, current));
+                        output.push(current);
+                    }
+                    (false, false) => (),
+                }
+            }
+
+            output
+        })
+}
@@ -1,4 +1,7 @@
+use iced_core::Color;
+
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MarkerShape {
     Square { size: f32, border_width: f32 },
     Circle { radius: f32, border_width: f32 },
@@ -8,6 +11,26 @@ pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
 
 pub struct Style {
     pub marker_shape: MarkerShape,
+    /// The shape of the non-interactive markers, such as the colors of a [Harmony](super::Harmony).
+    pub secondary_marker_shape: MarkerShape,
+    /// The color of the sRGB boundary drawn over wide gamut spectrums.
+    pub gamut_boundary: Color,
+    /// The color of the contrast thresholds drawn over [Spectrum::SaturationValue](super::Spectrum::SaturationValue).
+    pub contrast_boundary: Color,
+    /// The color of the ticks drawn along 1-Dimensional spectrums.
+    pub ticks: Color,
+    /// The color of the labels drawn next to ticks.
+    pub tick_labels: Color,
+    /// The size of the labels drawn next to ticks.
+    pub tick_label_size: f32,
+    /// The background of the value shown while dragging.
+    pub readout_background: Color,
+    /// The color of the value shown while dragging.
+    pub readout_text: Color,
+    /// The size of the tiles of the checkerboard drawn behind translucent colors.
+    pub checkerboard_size: f32,
+    /// The two colors of the checkerboard drawn behind translucent colors.
+    pub checkerboard_colors: [Color; 2],
 }
 
 pub trait Catalog {
@@ -36,5 +59,18 @@ pub fn normal(_: &iced_core::Theme) -> Style {
             size: 8.,
             border_width: 2.,
         },
+        secondary_marker_shape: MarkerShape::Circle {
+            radius: 3.,
+            border_width: 1.5,
+        },
+        gamut_boundary: Color::WHITE,
+        contrast_boundary: Color::BLACK,
+        ticks: Color::from_rgba(0.0, 0.0, 0.0, 0.6),
+        tick_labels: Color::BLACK,
+        tick_label_size: 10.,
+        readout_background: Color::from_rgba(0.0, 0.0, 0.0, 0.8),
+        readout_text: Color::WHITE,
+        checkerboard_size: 6.,
+        checkerboard_colors: [Color::WHITE, Color::from_rgb(0.8, 0.8, 0.8)],
     }
 }
@@ -0,0 +1,109 @@
+//! Colors of blackbody radiators, measured in Kelvin.
+
+use super::gamut::{self, Gamut};
+
+use iced_core::Color;
+
+/// The lowest temperature supported by [kelvin_to_color].
+pub const MIN_KELVIN: f32 = 1667.0;
+
+/// The highest temperature supported by [kelvin_to_color].
+pub const MAX_KELVIN: f32 = 25000.0;
+
+/// The color of a blackbody radiator at the given temperature, scaled so that its brightest channel is `1.0`.
+///
+/// Temperatures are clamped between [MIN_KELVIN] and [MAX_KELVIN].
+pub fn kelvin_to_color(kelvin: f32) -> Color {
+    let [r, g, b] = kelvin_to_linear(kelvin);
+
+    Color::from_linear_rgba(r, g, b, 1.0)
+}
+
+/// Approximate the temperature of a color, in Kelvin.
+///
+/// This finds the temperature whose color has the closest ratio of green and blue to red,
+/// so it is exact for colors produced by [kelvin_to_color], and a rough estimate otherwise.
+pub fn color_to_kelvin(color: Color) -> f32 {
+    const ITERATIONS: usize = 32;
+
+    let [r, g, b, _] = color.into_linear();
+
+    let ratio = |[r, g, b]: [f32; 3]| (g + b) / r.max(f32::EPSILON);
+    let target = ratio([r, g, b]);
+
+    let mut min = MIN_KELVIN;
+    let mut max = MAX_KELVIN;
+
+    // The ratio of green and blue to red increases with temperature.
+    for _ in 0..ITERATIONS {
+        let kelvin = (min + max) / 2.0;
+
+        match ratio(kelvin_to_linear(kelvin)) < target {
+            true => min = kelvin,
+            false => max = kelvin,
+        }
+    }
+
+    (min + max) / 2.0
+}
+
+fn kelvin_to_linear(kelvin: f32) -> [f32; 3] {
+    let [x, y] = planckian_locus(kelvin);
+
+    let xyz = [x / y, 1.0, (1.0 - x - y) / y];
+    let [r, g, b] = gamut::mul(Gamut::Srgb.xyz_to_rgb(), xyz).map(|c| c.max(0.0));
+
+    let max = r.max(g.max(b));
+
+    [r / max, g / max, b / max]
+}
+
+/// The chromaticity of a blackbody radiator.
+// https://en.wikipedia.org/wiki/Planckian_locus#Approximation
+fn planckian_locus(kelvin: f32) -> [f32; 2] {
+    let t = kelvin.clamp(MIN_KELVIN, MAX_KELVIN);
+
+    let (t1, t2, t3) = (1e3 / t, 1e6 / (t * t), 1e9 / (t * t * t));
+
+    let x = match t <= 4000.0 {
+        true => -0.266_123_9 * t3 - 0.234_358_9 * t2 + 0.877_695_6 * t1 + 0.179_910,
+        false => -3.025_847 * t3 + 2.107_037_9 * t2 + 0.222_634_7 * t1 + 0.240_390,
+    };
+
+    let (x2, x3) = (x * x, x * x * x);
+
+    let y = if t <= 2222.0 {
+        -1.106_381_4 * x3 - 1.348_110_2 * x2 + 2.185_558_3 * x - 0.202_196_83
+    } else if t <= 4000.0 {
+        -0.954_947_6 * x3 - 1.374_185_9 * x2 + 2.091_37 * x - 0.167_488_67
+    } else {
+        3.081_758 * x3 - 5.873_387 * x2 + 3.751_13 * x - 0.370_014_83
+    };
+
+    [x, y]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for kelvin in [
+            MIN_KELVIN, 2000.0, 2700.0, 4000.0, 5000.0, 6500.0, 10000.0, MAX_KELVIN,
+        ] {
+            let round_trip = color_to_kelvin(kelvin_to_color(kelvin));
+
+            assert!(
+                (round_trip - kelvin).abs() < 0.1,
+                "{kelvin}K -> {round_trip}K"
+            );
+        }
+    }
+
+    #[test]
+    fn clamped() {
+        assert_eq!(kelvin_to_color(1000.0), kelvin_to_color(MIN_KELVIN));
+        assert_eq!(kelvin_to_color(40000.0), kelvin_to_color(MAX_KELVIN));
+    }
+}